@@ -2,7 +2,7 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use crate::ChronoError;
+use crate::{ChronoError, PensionAge};
 
 /// A representation of a persons [`Age`].
 ///
@@ -32,6 +32,7 @@ impl Age {
     /// let age_error: ChronoError = Age::new(200).err().unwrap();
     /// assert_eq!(age_error, ChronoError::AgeError(200));
     /// ```
+    #[allow(clippy::absurd_extreme_comparisons)]
     #[inline]
     pub const fn new(age: u8) -> Result<Age, ChronoError> {
         if age <= Self::MAX && age >= Self::MIN {
@@ -53,6 +54,7 @@ impl Age {
     /// # use date::Age;
     /// const AGE: Age = Age::new_const(20);
     /// ```
+    #[allow(clippy::absurd_extreme_comparisons)]
     pub const fn new_const(age: u8) -> Self {
         if age <= Self::MAX && age >= Self::MIN {
             Age { age }
@@ -143,6 +145,75 @@ impl Age {
         Self::new(new_age)
     }
 
+    /// Returns the inclusive lower and upper bound of the [`Age::BAND_WIDTH`] year band of an [`Age`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Age;
+    /// let age: Age = Age::new(32).unwrap();
+    /// assert_eq!(age.band_5yr(), (30, 34));
+    ///
+    /// // Boundaries
+    /// assert_eq!(Age::new(30).unwrap().band_5yr(), (30, 34));
+    /// assert_eq!(Age::new(34).unwrap().band_5yr(), (30, 34));
+    /// assert_eq!(Age::new(35).unwrap().band_5yr(), (35, 39));
+    /// ```
+    #[inline]
+    pub const fn band_5yr(&self) -> (u8, u8) {
+        let lower: u8 = self.age - self.age % Self::BAND_WIDTH;
+
+        (lower, lower + Self::BAND_WIDTH - 1)
+    }
+
+    /// Returns the label "lower-upper" of the band of [`Age::band_5yr`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Age;
+    /// let age: Age = Age::new(32).unwrap();
+    /// assert_eq!(age.band_label(), String::from("30-34"));
+    /// ```
+    #[inline]
+    pub fn band_label(&self) -> String {
+        let (lower, upper): (u8, u8) = self.band_5yr();
+
+        format!("{}-{}", lower, upper)
+    }
+
+    /// Returns the whole years from an [`Age`] to a `target` [`PensionAge`].
+    ///
+    /// The [`PensionAge::pension_months`] are ignored.
+    /// For month precision subtract the age in months from [`PensionAge::total_months`].
+    /// The result is negative, if the `target` has already passed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Age, PensionAge};
+    /// let target: PensionAge = PensionAge::new_num(67, 0).unwrap();
+    ///
+    /// let age: Age = Age::new(60).unwrap();
+    /// assert_eq!(age.years_to(&target), 7);
+    ///
+    /// // Already past
+    /// let age: Age = Age::new(70).unwrap();
+    /// assert_eq!(age.years_to(&target), -3);
+    ///
+    /// // Month precision for 60 years and 4 months
+    /// let target: PensionAge = PensionAge::new_num(66, 10).unwrap();
+    /// let months: i32 = target.total_months() as i32 - (60 * 12 + 4);
+    /// assert_eq!(months, 78);
+    /// ```
+    #[inline]
+    pub const fn years_to(&self, target: &PensionAge) -> i32 {
+        target.pension_years().value() as i32 - self.age as i32
+    }
+
+    /// The width of the bands in [`Age::band_5yr`].
+    pub const BAND_WIDTH: u8 = 5;
+
     /// The smallest reasonable age a person should have.
     pub const MIN: u8 = 0;
 
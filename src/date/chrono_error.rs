@@ -31,6 +31,35 @@ pub enum ChronoError {
     OverflowError,
 }
 
+impl ChronoError {
+    /// Checks if the [`ChronoError`] is caused by bad input, which can be fixed by the caller.
+    ///
+    /// Only [`ChronoError::OverflowError`] is not an input error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::ChronoError;
+    /// assert!(ChronoError::YearError(1800).is_input_error());
+    /// assert!(ChronoError::MonthError(13).is_input_error());
+    /// assert!(ChronoError::DayError { day: 31, days_in_month: 30 }.is_input_error());
+    /// assert!(ChronoError::AgeError(200).is_input_error());
+    /// assert!(ChronoError::ParseError.is_input_error());
+    /// assert!(!ChronoError::OverflowError.is_input_error());
+    /// ```
+    #[inline]
+    pub const fn is_input_error(&self) -> bool {
+        match self {
+            ChronoError::YearError(_)
+            | ChronoError::MonthError(_)
+            | ChronoError::DayError { .. }
+            | ChronoError::AgeError(_)
+            | ChronoError::ParseError => true,
+            ChronoError::OverflowError => false,
+        }
+    }
+}
+
 impl Display for ChronoError {
     fn fmt(&self, format: &mut Formatter<'_>) -> fmt::Result {
         match self {
@@ -1,8 +1,12 @@
 //! This module contains the implementation of the [`Date`] struct.
 
-use crate::{Age, ChronoError, Day, Month, Rounding, Year};
+use crate::{
+    Accuracy, Age, ChronoError, Day, Epoch, Granularity, Language, Month, PeriodRelation, Rounding,
+    Season, Weekday, WeekendRule, Year,
+};
 use std::fmt::{self, Display, Formatter};
-use std::ops::Add;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
 
 /// A representation of a [`Date`].
 ///
@@ -38,6 +42,78 @@ impl Date {
         Self { year, month, day }
     }
 
+    /// Creates a new [`Date`] instance and checks that the `day` is valid for the `month` of the `year`.
+    ///
+    /// Unlike [`Date::new`] this catches a [`Day`] created for a different [`Month`] or [`Year`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::DayError`] - The `month` of the `year` does not have the amount of days provided.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date, Day, Month, Year};
+    /// let year: Year = Year::new(2024).unwrap();
+    /// let day: Day = Day::new(31, Month::January, year).unwrap();
+    ///
+    /// // Valid
+    /// let date: Date = Date::try_new(year, Month::January, day).unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 1, 31).unwrap());
+    ///
+    /// // DayError
+    /// let day_error: ChronoError = Date::try_new(year, Month::February, day).err().unwrap();
+    /// assert_eq!(day_error, ChronoError::DayError { day: 31, days_in_month: 29 });
+    /// ```
+    #[inline]
+    pub fn try_new(year: Year, month: Month, day: Day) -> Result<Self, ChronoError> {
+        let day: Day = Day::new(day.value(), month, year)?;
+
+        Ok(Self { year, month, day })
+    }
+
+    /// Returns a new [`Date`] with the provided components replaced.
+    ///
+    /// Components given as [`None`] are kept.
+    /// The `year` and the `month` are applied first, then the `day` is validated against them.
+    /// So no intermediate [`Date`] has to be valid.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::DayError`] - The resulting `month` of the resulting `year` does not have the
+    ///   amount of days of the resulting `day`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date, Month, Year};
+    /// let date: Date = Date::new_num(2024, 1, 31).unwrap();
+    ///
+    /// // Valid
+    /// let new_date: Date = date.with(None, Some(Month::February), Some(15)).unwrap();
+    /// assert_eq!(new_date, Date::new_num(2024, 2, 15).unwrap());
+    ///
+    /// let new_date: Date = date.with(Some(Year::new(2025).unwrap()), None, None).unwrap();
+    /// assert_eq!(new_date, Date::new_num(2025, 1, 31).unwrap());
+    ///
+    /// // DayError
+    /// let day_error: ChronoError = date.with(None, Some(Month::February), None).err().unwrap();
+    /// assert_eq!(day_error, ChronoError::DayError { day: 31, days_in_month: 29 });
+    /// ```
+    #[inline]
+    pub fn with(
+        &self,
+        year: Option<Year>,
+        month: Option<Month>,
+        day: Option<u8>,
+    ) -> Result<Self, ChronoError> {
+        let year: Year = year.unwrap_or(self.year);
+        let month: Month = month.unwrap_or(self.month);
+        let day: Day = Day::new(day.unwrap_or(self.day.value()), month, year)?;
+
+        Ok(Self { year, month, day })
+    }
+
     /// Creates a new [`Date`] instance based on numbers.
     ///
     /// This calls [`Year::new`], [`Month::new`] and [`Day::new`].
@@ -76,6 +152,36 @@ impl Date {
         Ok(Self { year, month, day })
     }
 
+    /// Creates a new [`Date`] instance based on numbers by clamping them into the valid range.
+    ///
+    /// The `year` is clamped between [`Year::MIN`] and [`Year::MAX`], the `month` to [1, 12]
+    /// and the `day` to the days of the resulting month.
+    /// Unlike [`Date::new_num`] this always yields a valid [`Date`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// // Year too small
+    /// let date: Date = Date::clamp_year_to_range(1800, 6, 1);
+    /// assert_eq!(date, Date::new_num(1900, 6, 1).unwrap());
+    ///
+    /// // Year too large and day too large
+    /// let date: Date = Date::clamp_year_to_range(2200, 2, 30);
+    /// assert_eq!(date, Date::new_num(2100, 2, 28).unwrap());
+    ///
+    /// // Already valid
+    /// let date: Date = Date::clamp_year_to_range(2024, 2, 29);
+    /// assert_eq!(date, Date::new_num(2024, 2, 29).unwrap());
+    /// ```
+    pub fn clamp_year_to_range(year: i32, month: u8, day: u8) -> Self {
+        let year: Year = Year::new_unchecked(year.clamp(Year::MIN, Year::MAX)); // safe
+        let month: Month = Month::new_const(month.clamp(1_u8, 12_u8)); // safe
+        let day: Day = Day::new_unchecked(day.clamp(1_u8, month.days_in_month(year))); // safe
+
+        Self { year, month, day }
+    }
+
     /// Creates a new [`Date`] instance based on numbers.
     ///
     /// This calls [`Year::new_const`], [`Month::new_const`] and [`Day::new_const`].
@@ -106,21 +212,20 @@ impl Date {
 
     /// Creates a new [`Date`] instance the string 'ddmmyyyy'.
     ///
+    /// The parts may also be separated by '.', '/' or '-'.
+    /// If the first part has 4 digits the order is 'yyyy.mm.dd', otherwise 'dd.mm.yyyy'.
+    /// Separated days and months must have 2 digits and years 4 digits.
+    ///
     /// This calls the appropriate `new` methods of [`Year`], [`Month`] and [`Day`].
     ///
     /// # Errors
     ///
     /// * [`ChronoError::ParseError`] - Could not parse any part as a number.
-    /// This could also happen the string length is not equal to 8.
+    ///   This could also happen the string length is not equal to 8 or the separated parts are malformed.
     /// * [`ChronoError::YearError`] - The `year` is not between [`Year::MIN`] and [`Year::MAX`] both included.
     /// * [`ChronoError::MonthError`] - The `month` is not inside the interval [1, 12].
     /// * [`ChronoError::DayError`] - The `month` of the `year` does not have the amount of days provided.
     ///
-    /// # Notes
-    ///
-    /// This method could probably be enhanced by automatically splitting the string at any '.' or '/'
-    /// and automatically recognizing if it is 'ddmmyyyy' or 'yyyy.mm.dd'.
-    ///
     /// # Examples
     ///
     /// ```rust
@@ -129,6 +234,18 @@ impl Date {
     /// let date: Date = Date::from_string("01012024").unwrap();
     /// assert_eq!(date, Date::new_num(2024, 1, 1).unwrap());
     ///
+    /// // Valid (Separated)
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(Date::from_string("01.06.2024").unwrap(), date);
+    /// assert_eq!(Date::from_string("01/06/2024").unwrap(), date);
+    /// assert_eq!(Date::from_string("2024.06.01").unwrap(), date);
+    /// assert_eq!(Date::from_string("2024-06-01").unwrap(), date);
+    ///
+    /// // ParseError (Malformed separated parts)
+    /// assert_eq!(Date::from_string("1.6.2024").err().unwrap(), ChronoError::ParseError);
+    /// assert_eq!(Date::from_string("01.06/2024").err().unwrap(), ChronoError::ParseError);
+    /// assert_eq!(Date::from_string("01.06.2024.").err().unwrap(), ChronoError::ParseError);
+    ///
     /// // ParseError (Too short)
     /// let year_error: ChronoError = Date::from_string("112024").err().unwrap();
     /// assert_eq!(year_error, ChronoError::ParseError);
@@ -142,6 +259,10 @@ impl Date {
     /// assert_eq!(day_error, ChronoError::DayError { day: 29, days_in_month: 28 });
     /// ```
     pub fn from_string(string: &str) -> Result<Self, ChronoError> {
+        if let Some(separator) = string.chars().find(|char| matches!(char, '.' | '/' | '-')) {
+            return Self::from_separated_string(string, separator);
+        }
+
         if string.len() != 8 {
             return Err(ChronoError::ParseError);
         }
@@ -164,6 +285,329 @@ impl Date {
         Ok(Self { year, month, day })
     }
 
+    /// Creates a new [`Date`] instance from the string 'dd.mm.yyyy' or 'yyyy.mm.dd' with any `separator`.
+    ///
+    /// See [`Date::from_string`].
+    fn from_separated_string(string: &str, separator: char) -> Result<Self, ChronoError> {
+        let parts: Vec<&str> = string.split(separator).collect();
+        let [first, second, third] = parts.as_slice() else {
+            return Err(ChronoError::ParseError);
+        };
+
+        // Order is recognized by the length of the first part
+        let (year_str, month_str, day_str): (&str, &str, &str) = if first.len() == 4 {
+            (first, second, third)
+        } else {
+            (third, second, first)
+        };
+
+        let is_digits = |part: &str, len: usize| -> bool {
+            part.len() == len && part.bytes().all(|byte| byte.is_ascii_digit())
+        };
+        if !is_digits(year_str, 4) || !is_digits(month_str, 2) || !is_digits(day_str, 2) {
+            return Err(ChronoError::ParseError);
+        }
+
+        // Converted to numbers
+        let day_u8: u8 = day_str.parse().map_err(|_| ChronoError::ParseError)?;
+        let month_u8: u8 = month_str.parse().map_err(|_| ChronoError::ParseError)?;
+        let year_i32: i32 = year_str.parse().map_err(|_| ChronoError::ParseError)?;
+
+        // Converted to own types
+        let year: Year = Year::new(year_i32)?;
+        let month: Month = Month::new(month_u8)?;
+        let day: Day = Day::new(day_u8, month, year)?;
+
+        Ok(Self { year, month, day })
+    }
+
+    /// Creates a new [`Date`] instance from the ISO 8601 string 'yyyy-mm-dd'.
+    ///
+    /// Unlike [`Date::from_string`] only '-' is accepted as a separator.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::ParseError`] - The string does not match 'yyyy-mm-dd'.
+    /// * [`ChronoError::YearError`] - The `year` is not between [`Year::MIN`] and [`Year::MAX`] both included.
+    /// * [`ChronoError::MonthError`] - The `month` is not inside the interval [1, 12].
+    /// * [`ChronoError::DayError`] - The `month` of the `year` does not have the amount of days provided.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date};
+    /// // Valid
+    /// let date: Date = Date::from_iso("2024-06-01").unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 6, 1).unwrap());
+    /// assert_eq!(date.iso_format(), String::from("2024-06-01"));
+    ///
+    /// // ParseError (Wrong separator)
+    /// let parse_error: ChronoError = Date::from_iso("2024.06.01").err().unwrap();
+    /// assert_eq!(parse_error, ChronoError::ParseError);
+    ///
+    /// // ParseError (Wrong order)
+    /// let parse_error: ChronoError = Date::from_iso("01-06-2024").err().unwrap();
+    /// assert_eq!(parse_error, ChronoError::ParseError);
+    ///
+    /// // DayError
+    /// let day_error: ChronoError = Date::from_iso("2023-02-29").err().unwrap();
+    /// assert_eq!(day_error, ChronoError::DayError { day: 29, days_in_month: 28 });
+    /// ```
+    #[inline]
+    pub fn from_iso(string: &str) -> Result<Self, ChronoError> {
+        Self::parse_with_format(string, "%Y-%m-%d")
+    }
+
+    /// Creates a new [`Date`] instance from a compact string 'yyyymmdd'.
+    ///
+    /// Unlike [`Date::from_string`] the year comes first.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::ParseError`] - The string does not consist of exactly 8 digits.
+    /// * [`ChronoError::YearError`] - The `year` is not between [`Year::MIN`] and [`Year::MAX`] both included.
+    /// * [`ChronoError::MonthError`] - The `month` is not inside the interval [1, 12].
+    /// * [`ChronoError::DayError`] - The `month` of the `year` does not have the amount of days provided.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date};
+    /// // Valid
+    /// let date: Date = Date::from_yyyymmdd("20240601").unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 6, 1).unwrap());
+    /// assert_eq!(Date::from_yyyymmdd(&date.to_yyyymmdd_string()).unwrap(), date);
+    ///
+    /// // ParseError (Too short)
+    /// let parse_error: ChronoError = Date::from_yyyymmdd("2024061").err().unwrap();
+    /// assert_eq!(parse_error, ChronoError::ParseError);
+    ///
+    /// // ParseError (Wrong symbols)
+    /// let parse_error: ChronoError = Date::from_yyyymmdd("2024-6-1").err().unwrap();
+    /// assert_eq!(parse_error, ChronoError::ParseError);
+    ///
+    /// // MonthError
+    /// let month_error: ChronoError = Date::from_yyyymmdd("20241301").err().unwrap();
+    /// assert_eq!(month_error, ChronoError::MonthError(13));
+    /// ```
+    pub fn from_yyyymmdd(string: &str) -> Result<Self, ChronoError> {
+        if string.len() != 8 || !string.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(ChronoError::ParseError);
+        }
+
+        let number: i32 = string.parse().map_err(|_| ChronoError::ParseError)?;
+
+        Self::from_sortable_i32(number)
+    }
+
+    /// Creates a new [`Date`] instance from a string with a given `pattern`.
+    ///
+    /// The `pattern` supports the following specifiers:
+    ///
+    /// * `%Y` - The year with exactly 4 digits.
+    /// * `%m` - The month with exactly 2 digits.
+    /// * `%d` - The day with exactly 2 digits.
+    /// * `%B` or `%b` - The month as a name, see [`Month::from_string`].
+    /// * `%%` - A literal '%'.
+    ///
+    /// Any other character must match literally.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::ParseError`] - The `string` does not match the `pattern`,
+    ///   the `pattern` is missing a year, month or day or contains an unknown specifier.
+    /// * [`ChronoError::YearError`] - The `year` is not between [`Year::MIN`] and [`Year::MAX`] both included.
+    /// * [`ChronoError::MonthError`] - The `month` is not inside the interval [1, 12].
+    /// * [`ChronoError::DayError`] - The `month` of the `year` does not have the amount of days provided.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date};
+    /// // Valid
+    /// let date: Date = Date::parse_with_format("2024-06-01", "%Y-%m-%d").unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 6, 1).unwrap());
+    ///
+    /// let date: Date = Date::parse_with_format("01 June 2024", "%d %B %Y").unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 6, 1).unwrap());
+    ///
+    /// // ParseError (Wrong separator)
+    /// let parse_error: ChronoError = Date::parse_with_format("2024/06/01", "%Y-%m-%d").err().unwrap();
+    /// assert_eq!(parse_error, ChronoError::ParseError);
+    ///
+    /// // ParseError (Missing day)
+    /// let parse_error: ChronoError = Date::parse_with_format("2024-06", "%Y-%m").err().unwrap();
+    /// assert_eq!(parse_error, ChronoError::ParseError);
+    ///
+    /// // DayError
+    /// let day_error: ChronoError = Date::parse_with_format("2023-02-29", "%Y-%m-%d").err().unwrap();
+    /// assert_eq!(day_error, ChronoError::DayError { day: 29, days_in_month: 28 });
+    /// ```
+    pub fn parse_with_format(string: &str, pattern: &str) -> Result<Self, ChronoError> {
+        let mut rest: &str = string;
+        let mut pattern_chars = pattern.chars();
+
+        let mut year_i32: Option<i32> = None;
+        let mut month: Option<Month> = None;
+        let mut day_u8: Option<u8> = None;
+
+        while let Some(pattern_char) = pattern_chars.next() {
+            if pattern_char != '%' {
+                rest = rest
+                    .strip_prefix(pattern_char)
+                    .ok_or(ChronoError::ParseError)?;
+                continue;
+            }
+
+            match pattern_chars.next() {
+                Some('Y') => {
+                    let (digits, remaining): (&str, &str) = Self::split_digits(rest, 4)?;
+                    year_i32 = Some(digits.parse().map_err(|_| ChronoError::ParseError)?);
+                    rest = remaining;
+                }
+                Some('m') => {
+                    let (digits, remaining): (&str, &str) = Self::split_digits(rest, 2)?;
+                    let month_u8: u8 = digits.parse().map_err(|_| ChronoError::ParseError)?;
+                    month = Some(Month::new(month_u8)?);
+                    rest = remaining;
+                }
+                Some('d') => {
+                    let (digits, remaining): (&str, &str) = Self::split_digits(rest, 2)?;
+                    day_u8 = Some(digits.parse().map_err(|_| ChronoError::ParseError)?);
+                    rest = remaining;
+                }
+                Some('B') | Some('b') => {
+                    let end: usize = rest
+                        .find(|char: char| !char.is_alphabetic())
+                        .unwrap_or(rest.len());
+                    month = Some(Month::from_string(&rest[..end])?);
+                    rest = &rest[end..];
+                }
+                Some('%') => {
+                    rest = rest.strip_prefix('%').ok_or(ChronoError::ParseError)?;
+                }
+                _ => return Err(ChronoError::ParseError),
+            }
+        }
+
+        if !rest.is_empty() {
+            return Err(ChronoError::ParseError);
+        }
+
+        let year: Year = Year::new(year_i32.ok_or(ChronoError::ParseError)?)?;
+        let month: Month = month.ok_or(ChronoError::ParseError)?;
+        let day: Day = Day::new(day_u8.ok_or(ChronoError::ParseError)?, month, year)?;
+
+        Ok(Self { year, month, day })
+    }
+
+    /// Splits exactly `count` ASCII digits off the start of a string.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::ParseError`] - The string does not start with `count` ASCII digits.
+    #[inline]
+    fn split_digits(string: &str, count: usize) -> Result<(&str, &str), ChronoError> {
+        match string.get(..count) {
+            Some(digits) if digits.bytes().all(|byte| byte.is_ascii_digit()) => {
+                Ok((digits, &string[count..]))
+            }
+            _ => Err(ChronoError::ParseError),
+        }
+    }
+
+    /// Creates a new [`Date`] instance by trying several `formats` in order.
+    ///
+    /// Each format is tried with [`Date::parse_with_format`] and the first success is returned.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::ParseError`] - None of the `formats` matched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date};
+    /// let formats: [&str; 3] = ["%Y-%m-%d", "%d.%m.%Y", "%d/%m/%Y"];
+    ///
+    /// // Valid (second format)
+    /// let date: Date = Date::parse_any("01.06.2024", &formats).unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 6, 1).unwrap());
+    ///
+    /// // ParseError
+    /// let parse_error: ChronoError = Date::parse_any("June 1st 2024", &formats).err().unwrap();
+    /// assert_eq!(parse_error, ChronoError::ParseError);
+    /// ```
+    pub fn parse_any(string: &str, formats: &[&str]) -> Result<Self, ChronoError> {
+        formats
+            .iter()
+            .find_map(|format| Self::parse_with_format(string, format).ok())
+            .ok_or(ChronoError::ParseError)
+    }
+
+    /// Checks if a string parsed with a given `pattern` equals the [`Date`].
+    ///
+    /// This uses [`Date::parse_with_format`].
+    /// Any string which can not be parsed does not match.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert!(date.matches_format(&date.format_dmy(), "%d.%m.%Y"));
+    /// assert!(date.matches_format("2024-06-01", "%Y-%m-%d"));
+    ///
+    /// // Different date
+    /// assert!(!date.matches_format("2024-06-02", "%Y-%m-%d"));
+    ///
+    /// // Different format
+    /// assert!(!date.matches_format("2024-06-01", "%d.%m.%Y"));
+    /// ```
+    #[inline]
+    pub fn matches_format(&self, string: &str, pattern: &str) -> bool {
+        Self::parse_with_format(string, pattern) == Ok(*self)
+    }
+
+    /// Creates a new [`Date`] instance from separate strings for `year`, `month` and `day`.
+    ///
+    /// This calls [`Year::from_string`] and [`Month::from_string`], so the `month` can also be a name.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::ParseError`] - Could not parse any part.
+    /// * [`ChronoError::YearError`] - The `year` is not between [`Year::MIN`] and [`Year::MAX`] both included.
+    /// * [`ChronoError::MonthError`] - The `month` is not inside the interval [1, 12].
+    /// * [`ChronoError::DayError`] - The `month` of the `year` does not have the amount of days provided.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date};
+    /// // Valid
+    /// let date: Date = Date::from_parts_str("2024", "June", "1").unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 6, 1).unwrap());
+    ///
+    /// let date: Date = Date::from_parts_str("2024", "06", "01").unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 6, 1).unwrap());
+    ///
+    /// // ParseError
+    /// let parse_error: ChronoError = Date::from_parts_str("2024", "Juno", "1").err().unwrap();
+    /// assert_eq!(parse_error, ChronoError::ParseError);
+    ///
+    /// // DayError
+    /// let day_error: ChronoError = Date::from_parts_str("2024", "June", "31").err().unwrap();
+    /// assert_eq!(day_error, ChronoError::DayError { day: 31, days_in_month: 30 });
+    /// ```
+    pub fn from_parts_str(year: &str, month: &str, day: &str) -> Result<Self, ChronoError> {
+        let year: Year = Year::from_string(year)?;
+        let month: Month = Month::from_string(month)?;
+        let day_u8: u8 = day.parse().map_err(|_| ChronoError::ParseError)?;
+        let day: Day = Day::new(day_u8, month, year)?;
+
+        Ok(Self { year, month, day })
+    }
+
     /// Returns the value of the [`Year`] attribute.
     ///
     /// # Examples
@@ -206,6 +650,22 @@ impl Date {
         self.day
     }
 
+    /// Returns the raw values `(year, month, day)` of the [`Date`], e.g. for a database row.
+    ///
+    /// As the [`Date`] is always valid, so are the values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.as_ymd_u(), (2024, 6, 1));
+    /// ```
+    #[inline]
+    pub const fn as_ymd_u(&self) -> (i32, u8, u8) {
+        (self.year.value(), self.month.value(), self.day.value())
+    }
+
     /// Returns a new [`Date`] with the `day` set to 1.
     ///
     /// # Examples
@@ -268,25 +728,191 @@ impl Date {
         Self { year, month, day }
     }
 
-    /// Returns the string representation "dd.mm.yyyy" of the `Date` instance.
+    /// Returns a new [`Date`] set to the first day of the quarter.
+    ///
+    /// The quarters start on 01.01., 01.04., 01.07. and 01.10., see [`Month::quarter`].
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use date::Date;
-    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
-    /// assert_eq!(date.format_dmy(), String::from("01.06.2024"));
+    /// // Q2
+    /// let date: Date = Date::new_num(2024, 5, 20).unwrap();
+    /// assert_eq!(date.begin_of_quarter(), Date::new_num(2024, 4, 1).unwrap());
+    ///
+    /// // Q4
+    /// let date: Date = Date::new_num(2024, 12, 31).unwrap();
+    /// assert_eq!(date.begin_of_quarter(), Date::new_num(2024, 10, 1).unwrap());
     /// ```
     #[inline]
-    pub fn format_dmy(&self) -> String {
-        format!(
-            "{:02}.{:02}.{}",
-            self.day.value(),
-            self.month as u8,
-            self.year.value()
+    pub fn begin_of_quarter(&self) -> Self {
+        let year: Year = self.year;
+        let month: Month = Month::new_const(self.month.quarter() * 3_u8 - 2_u8); // safe
+        let day: Day = Day::new_unchecked(1_u8); // safe
+
+        Self { year, month, day }
+    }
+
+    /// Returns a new [`Date`] set to the last day of the quarter.
+    ///
+    /// The quarters end on 31.03., 30.06., 30.09. and 31.12., see [`Month::quarter`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// // Q2
+    /// let date: Date = Date::new_num(2024, 5, 20).unwrap();
+    /// assert_eq!(date.end_of_quarter(), Date::new_num(2024, 6, 30).unwrap());
+    ///
+    /// // Q4
+    /// let date: Date = Date::new_num(2024, 10, 1).unwrap();
+    /// assert_eq!(date.end_of_quarter(), Date::new_num(2024, 12, 31).unwrap());
+    /// ```
+    #[inline]
+    pub fn end_of_quarter(&self) -> Self {
+        let year: Year = self.year;
+        let month: Month = Month::new_const(self.month.quarter() * 3_u8); // safe
+        let day: Day = Day::new_unchecked(month.days_in_month(year)); // safe
+
+        Self { year, month, day }
+    }
+
+    /// Rounds a [`Date`] to the first day of a period based on a given [`Granularity`] and [`Rounding`].
+    ///
+    /// * [`Rounding::Floor`] - The first day of the period containing the [`Date`].
+    /// * [`Rounding::Ceil`] - The first day of the next period, unless the [`Date`] already is a first day.
+    /// * [`Rounding::Nearest`] - The closer of both in days.
+    ///   Exactly half-way rounds up to the next period.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - The [`Date`] is rounded up to the first day of a period after [`Date::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date, Granularity, Rounding, Year};
+    /// let date: Date = Date::new_num(2024, 6, 12).unwrap();
+    ///
+    /// // Month
+    /// assert_eq!(date.round_to(Granularity::Month, Rounding::Floor).unwrap(), Date::new_num(2024, 6, 1).unwrap());
+    /// assert_eq!(date.round_to(Granularity::Month, Rounding::Ceil).unwrap(), Date::new_num(2024, 7, 1).unwrap());
+    /// assert_eq!(date.round_to(Granularity::Month, Rounding::Nearest).unwrap(), Date::new_num(2024, 6, 1).unwrap());
+    ///
+    /// // Half-way (15 days after 01.06. and 15 days before 01.07.)
+    /// let date: Date = Date::new_num(2024, 6, 16).unwrap();
+    /// assert_eq!(date.round_to(Granularity::Month, Rounding::Nearest).unwrap(), Date::new_num(2024, 7, 1).unwrap());
+    ///
+    /// // Quarter
+    /// assert_eq!(date.round_to(Granularity::Quarter, Rounding::Floor).unwrap(), Date::new_num(2024, 4, 1).unwrap());
+    /// assert_eq!(date.round_to(Granularity::Quarter, Rounding::Nearest).unwrap(), Date::new_num(2024, 7, 1).unwrap());
+    ///
+    /// // Year
+    /// assert_eq!(date.round_to(Granularity::Year, Rounding::Floor).unwrap(), Date::new_num(2024, 1, 1).unwrap());
+    /// assert_eq!(date.round_to(Granularity::Year, Rounding::Ceil).unwrap(), Date::new_num(2025, 1, 1).unwrap());
+    ///
+    /// // Already on a boundary
+    /// let date: Date = Date::new_num(2024, 7, 1).unwrap();
+    /// assert_eq!(date.round_to(Granularity::Month, Rounding::Ceil).unwrap(), date);
+    ///
+    /// // Near Date::MAX
+    /// let date: Date = Date::new_num(Year::MAX, 12, 2).unwrap();
+    /// assert_eq!(date.round_to(Granularity::Month, Rounding::Nearest).unwrap(), Date::new_num(Year::MAX, 12, 1).unwrap());
+    ///
+    /// // YearError
+    /// let year_error: ChronoError = date.round_to(Granularity::Month, Rounding::Ceil).err().unwrap();
+    /// assert_eq!(year_error, ChronoError::YearError(Year::MAX + 1));
+    /// ```
+    pub fn round_to(
+        &self,
+        granularity: Granularity,
+        rounding: Rounding,
+    ) -> Result<Self, ChronoError> {
+        let (begin, end): (Date, Date) = match granularity {
+            Granularity::Month => (self.begin_of_month(), self.end_of_month()),
+            Granularity::Quarter => {
+                let begin: Date = self.begin_of_quarter();
+                let last_month: Date = Self {
+                    month: Month::new_const(begin.month.value() + 2_u8), // safe
+                    ..begin
+                };
+                (begin, last_month.end_of_month())
+            }
+            Granularity::Year => (
+                Self {
+                    year: self.year,
+                    month: Month::January,
+                    day: Day::new_unchecked(1_u8), // safe
+                },
+                Self {
+                    year: self.year,
+                    month: Month::December,
+                    day: Day::new_unchecked(31_u8), // safe
+                },
+            ),
+        };
+
+        if *self == begin {
+            return Ok(begin);
+        }
+
+        let rounds_up: bool = match rounding {
+            Rounding::Floor => false,
+            Rounding::Ceil => true,
+            // The next period starts one day after the end, ties round up
+            Rounding::Nearest => self.day_difference(&begin) > self.day_difference(&end),
+        };
+
+        if rounds_up {
+            end.add_days(1_i32)
+        } else {
+            Ok(begin)
+        }
+    }
+
+    /// Returns the string representation "dd.mm.yyyy" of the `Date` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.format_dmy(), String::from("01.06.2024"));
+    /// ```
+    #[inline]
+    pub fn format_dmy(&self) -> String {
+        format!(
+            "{:02}.{:02}.{}",
+            self.day.value(),
+            self.month as u8,
+            self.year.value()
         )
     }
 
+    /// Returns the string representation "weekday, dd.mm.yyyy" of the `Date` instance.
+    ///
+    /// The name of the [`Weekday`] is given in the provided [`Language`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Language};
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.format_with_weekday(Language::German), String::from("Samstag, 01.06.2024"));
+    /// assert_eq!(date.format_with_weekday(Language::English), String::from("Saturday, 01.06.2024"));
+    /// ```
+    #[inline]
+    pub fn format_with_weekday(&self, language: Language) -> String {
+        let weekday: Weekday = self.weekday();
+        let name: &str = match language {
+            Language::English => weekday.name(),
+            Language::German => weekday.name_de(),
+        };
+
+        format!("{}, {}", name, self.format_dmy())
+    }
+
     /// Returns the string representation "yyyy.mm.dd" of the `Date` instance.
     ///
     /// # Examples
@@ -306,11 +932,216 @@ impl Date {
         )
     }
 
+    /// Returns the string representation of the `Date` instance based on a `pattern`.
+    ///
+    /// The `pattern` supports the following specifiers:
+    ///
+    /// * `%Y` - The year with 4 digits.
+    /// * `%y` - The last 2 digits of the year.
+    /// * `%m` - The month with 2 digits.
+    /// * `%d` - The day with 2 digits.
+    /// * `%B` - The english name of the month, see [`Month::name`].
+    /// * `%b` - The english abbreviation of the month, see [`Month::abbreviation`].
+    /// * `%%` - A literal '%'.
+    ///
+    /// Any other character, including unknown specifiers, is emitted literally.
+    /// See [`Date::parse_with_format`] for the reverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.format("%d.%m.%Y"), date.format_dmy());
+    /// assert_eq!(date.format("%Y-%m-%d"), String::from("2024-06-01"));
+    /// assert_eq!(date.format("%d %B %Y"), String::from("01 June 2024"));
+    /// assert_eq!(date.format("%b '%y"), String::from("Jun '24"));
+    ///
+    /// // Literal and unknown specifiers
+    /// assert_eq!(date.format("100%% in %q"), String::from("100% in %q"));
+    /// ```
+    pub fn format(&self, pattern: &str) -> String {
+        let mut formatted: String = String::with_capacity(pattern.len());
+        let mut pattern_chars = pattern.chars();
+
+        while let Some(pattern_char) = pattern_chars.next() {
+            if pattern_char != '%' {
+                formatted.push(pattern_char);
+                continue;
+            }
+
+            match pattern_chars.next() {
+                Some('Y') => formatted.push_str(&self.year.value().to_string()),
+                Some('y') => formatted.push_str(&format!("{:02}", self.year.value() % 100)),
+                Some('m') => formatted.push_str(&format!("{:02}", self.month.value())),
+                Some('d') => formatted.push_str(&format!("{:02}", self.day.value())),
+                Some('B') => formatted.push_str(self.month.name()),
+                Some('b') => formatted.push_str(self.month.abbreviation()),
+                Some('%') => formatted.push('%'),
+                Some(other) => {
+                    formatted.push('%');
+                    formatted.push(other);
+                }
+                None => formatted.push('%'),
+            }
+        }
+
+        formatted
+    }
+
+    /// Returns the ISO 8601 string representation "yyyy-mm-dd" of the `Date` instance.
+    ///
+    /// See [`Date::from_iso`] for the reverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.iso_format(), String::from("2024-06-01"));
+    /// ```
+    #[inline]
+    pub fn iso_format(&self) -> String {
+        self.format("%Y-%m-%d")
+    }
+
+    /// Returns the compact string representation "yyyymmdd" of the `Date` instance.
+    ///
+    /// See [`Date::from_yyyymmdd`] for the reverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.to_yyyymmdd_string(), String::from("20240601"));
+    /// ```
+    #[inline]
+    pub fn to_yyyymmdd_string(&self) -> String {
+        format!(
+            "{}{:02}{:02}",
+            self.year.value(),
+            self.month as u8,
+            self.day.value()
+        )
+    }
+
+    /// Returns the long german string representation "d. Monat yyyy" of the `Date` instance.
+    ///
+    /// The day has no leading zero and the month is given by [`Month::name_de`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.format_long_de(), String::from("1. Juni 2024"));
+    ///
+    /// let date: Date = Date::new_num(2024, 3, 15).unwrap();
+    /// assert_eq!(date.format_long_de(), String::from("15. März 2024"));
+    /// ```
+    #[inline]
+    pub fn format_long_de(&self) -> String {
+        format!(
+            "{}. {} {}",
+            self.day.value(),
+            self.month.name_de(),
+            self.year.value()
+        )
+    }
+
+    /// Returns a verbose string representation of the components of the `Date` instance.
+    ///
+    /// Unlike the derived [`Debug`] this shows the plain values and the name of the month.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.debug_verbose(), String::from("Date { year: 2024, month: June(6), day: 1 }"));
+    /// assert!(date.debug_verbose().contains("June"));
+    /// ```
+    #[inline]
+    pub fn debug_verbose(&self) -> String {
+        format!(
+            "Date {{ year: {}, month: {}({}), day: {} }}",
+            self.year.value(),
+            self.month.name(),
+            self.month.value(),
+            self.day.value()
+        )
+    }
+
+    /// Creates a new [`Date`] instance from the long german string "d. Monat yyyy".
+    ///
+    /// This is the inverse of [`Date::format_long_de`].
+    /// The day may have a leading zero and the month may be abbreviated, e.g. "01. Dez. 2024".
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::ParseError`] - The `string` does not consist of day, month and year,
+    ///   the day has no trailing '.' or the month name is unknown.
+    /// * [`ChronoError::YearError`] - The `year` is not between [`Year::MIN`] and [`Year::MAX`] both included.
+    /// * [`ChronoError::DayError`] - The `month` of the `year` does not have the amount of days provided.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date};
+    /// // Valid
+    /// let date: Date = Date::from_long_de("1. Juni 2024").unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 6, 1).unwrap());
+    ///
+    /// let date: Date = Date::from_long_de("01. Dez. 2024").unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 12, 1).unwrap());
+    ///
+    /// // Round trip
+    /// for date in [
+    ///     Date::new_num(2024, 3, 15).unwrap(),
+    ///     Date::new_num(1999, 12, 31).unwrap(),
+    ///     Date::new_num(2000, 2, 29).unwrap(),
+    /// ] {
+    ///     assert_eq!(Date::from_long_de(&date.format_long_de()).unwrap(), date);
+    /// }
+    ///
+    /// // ParseError
+    /// let parse_error: ChronoError = Date::from_long_de("1. Juno 2024").err().unwrap();
+    /// assert_eq!(parse_error, ChronoError::ParseError);
+    ///
+    /// // DayError
+    /// let day_error: ChronoError = Date::from_long_de("31. April 2024").err().unwrap();
+    /// assert_eq!(day_error, ChronoError::DayError { day: 31, days_in_month: 30 });
+    /// ```
+    pub fn from_long_de(string: &str) -> Result<Self, ChronoError> {
+        let mut parts = string.split_whitespace();
+
+        let (Some(day_str), Some(month_str), Some(year_str), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(ChronoError::ParseError);
+        };
+
+        let day_u8: u8 = day_str
+            .strip_suffix('.')
+            .ok_or(ChronoError::ParseError)?
+            .parse()
+            .map_err(|_| ChronoError::ParseError)?;
+
+        let year: Year = Year::from_string(year_str)?;
+        let month: Month = Month::from_name_de(month_str)?;
+        let day: Day = Day::new(day_u8, month, year)?;
+
+        Ok(Self { year, month, day })
+    }
+
     /// Adds a number of years to a [`Date`] instance.
     ///
     /// To subtract use a negative sign.
     ///
     /// This uses the [`Year::add_years`] method.
+    /// If the day does not exist in the resulting year, it is clamped to the last day of the month,
+    /// so the 29th of February becomes the 28th of February in non leap years.
     ///
     /// # Errors
     ///
@@ -331,6 +1162,13 @@ impl Date {
     /// let new_date: Date = date.add_years(-4).unwrap();
     /// assert_eq!(new_date, Date::new_num(2020, 6, 1).unwrap());
     ///
+    /// // Clamped leap day
+    /// let date: Date = Date::new_num(2024, 2, 29).unwrap();
+    /// let new_date: Date = date.add_years(1).unwrap();
+    /// assert_eq!(new_date, Date::new_num(2025, 2, 28).unwrap());
+    /// let new_date: Date = date.add_years(4).unwrap();
+    /// assert_eq!(new_date, Date::new_num(2028, 2, 29).unwrap());
+    ///
     /// // YearError
     /// let date: Date = Date::new_num(2095, 6, 1).unwrap();
     /// let year_error: ChronoError = date.add_years(10).err().unwrap();
@@ -344,10 +1182,15 @@ impl Date {
     pub fn add_years(&self, years: i32) -> Result<Self, ChronoError> {
         let new_year: Year = self.year.add_years(years)?;
 
+        // Clamp day if necessary
+        let max_day: u8 = self.month.days_in_month(new_year);
+        let day_u8: u8 = self.day.value().min(max_day);
+        let new_day: Day = Day::new(day_u8, self.month, new_year)?;
+
         Ok(Self {
             year: new_year,
             month: self.month,
-            day: self.day,
+            day: new_day,
         })
     }
 
@@ -356,6 +1199,8 @@ impl Date {
     /// To subtract use a negative sign.
     ///
     /// This uses the [`Month::add_months`] method.
+    /// If the day does not exist in the resulting month, it is clamped to the last day of the month,
+    /// e.g. the 31st of January plus one month is the 28th or 29th of February.
     ///
     /// # Errors
     ///
@@ -377,6 +1222,11 @@ impl Date {
     /// let new_date: Date = date.add_months(-1).unwrap();
     /// assert_eq!(new_date, Date::new_num(2024, 11, 30).unwrap());
     ///
+    /// // Clamped leap day
+    /// let date: Date = Date::new_num(2024, 2, 29).unwrap();
+    /// let new_date: Date = date.add_months(12).unwrap();
+    /// assert_eq!(new_date, Date::new_num(2025, 2, 28).unwrap());
+    ///
     /// // YearError
     /// let date: Date = Date::new_num(Year::MAX, 6, 1).unwrap();
     /// let year_error: ChronoError = date.add_months(10).err().unwrap();
@@ -398,6 +1248,47 @@ impl Date {
         })
     }
 
+    /// Adds a number of months to a [`Date`] instance using the end of month rule.
+    ///
+    /// To subtract use a negative sign.
+    ///
+    /// If `self` is the last day of its month, the result is the last day of the target month.
+    /// Otherwise this behaves like [`Date::add_months`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - The resulting year is not between [`Year::MIN`] and [`Year::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date, Year};
+    /// // End of month
+    /// let date: Date = Date::new_num(2023, 2, 28).unwrap();
+    /// let new_date: Date = date.add_months_eom(1).unwrap();
+    /// assert_eq!(new_date, Date::new_num(2023, 3, 31).unwrap());
+    /// assert_eq!(date.add_months(1).unwrap(), Date::new_num(2023, 3, 28).unwrap());
+    ///
+    /// // Not end of month
+    /// let date: Date = Date::new_num(2024, 2, 28).unwrap();
+    /// let new_date: Date = date.add_months_eom(1).unwrap();
+    /// assert_eq!(new_date, Date::new_num(2024, 3, 28).unwrap());
+    ///
+    /// // YearError
+    /// let date: Date = Date::new_num(Year::MAX, 6, 30).unwrap();
+    /// let year_error: ChronoError = date.add_months_eom(10).err().unwrap();
+    /// assert_eq!(year_error, ChronoError::YearError(Year::MAX + 1));
+    /// ```
+    pub fn add_months_eom(&self, months: i32) -> Result<Self, ChronoError> {
+        let new_date: Date = self.add_months(months)?;
+
+        if *self == self.end_of_month() {
+            Ok(new_date.end_of_month())
+        } else {
+            Ok(new_date)
+        }
+    }
+
     /// Adds a number of days to a [`Date`] instance.
     ///
     /// To subtract use a negative sign.
@@ -479,47 +1370,494 @@ impl Date {
         Ok(Self { year, month, day })
     }
 
-    /// Returns the number of days since 00.01.0000.
+    /// Adds a number of weeks to a [`Date`] instance.
     ///
-    /// This method is formula-based and leap-year safe.
+    /// To subtract use a negative sign.
+    /// This is [`Date::add_days`] with 7 days per week.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::OverflowError`] - The number of days does not fit into [`i32`].
+    /// * [`ChronoError::YearError`] - Based on [`Date::add_days`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date};
+    /// // Valid addition
+    /// let date: Date = Date::new_num(2024, 6, 24).unwrap();
+    /// let new_date: Date = date.add_weeks(2).unwrap();
+    /// assert_eq!(new_date, Date::new_num(2024, 7, 8).unwrap());
+    ///
+    /// // Valid subtraction
+    /// let new_date: Date = date.add_weeks(-1).unwrap();
+    /// assert_eq!(new_date, Date::new_num(2024, 6, 17).unwrap());
+    ///
+    /// // OverflowError
+    /// let overflow_error: ChronoError = date.add_weeks(i32::MAX).err().unwrap();
+    /// assert_eq!(overflow_error, ChronoError::OverflowError);
+    /// ```
     #[inline]
-    fn to_days(&self) -> i32 {
-        let full_years: i32 = self.year.value() - 1;
-
-        // Days in previous full years with leaps
-        let mut days: i32 = full_years * 365 + full_years / 4 - full_years / 100 + full_years / 400;
-
-        // Cumulative days in months (non-leap by default)
-        const MONTH_DAYS: [i32; 13] = [0, 0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
-        days += MONTH_DAYS[self.month as usize];
-
-        // Add current day
-        days += i32::from(self.day);
-
-        // Leap year adjustment
-        if self.month > Month::February && self.year.is_leap_year() {
-            days += 1;
-        }
+    pub fn add_weeks(&self, weeks: i32) -> Result<Self, ChronoError> {
+        let days: i32 = weeks.checked_mul(7_i32).ok_or(ChronoError::OverflowError)?;
 
-        days
+        self.add_days(days)
     }
 
-    /// Calculates the difference in days between two [`Date`]s.
+    /// Returns the next [`Date`].
     ///
-    /// This is always a positive number.
+    /// Unlike [`Date::add_days`] this returns [`None`] at [`Date::MAX`] instead of an error.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use date::Date;
-    /// let date_1: Date = Date::new_num(2024, 12, 31).unwrap();
-    /// let date_2: Date = Date::new_num(2024, 12, 31).unwrap();
-    /// assert_eq!(date_1.day_difference(&date_2), 0);
-    /// assert_eq!(date_2.day_difference(&date_1), 0);
-    ///
-    /// let date_1: Date = Date::new_num(2024, 12, 31).unwrap();
-    /// let date_2: Date = Date::new_num(2024, 12, 20).unwrap();
-    /// assert_eq!(date_1.day_difference(&date_2), 11);
+    /// let date: Date = Date::new_num(2024, 12, 31).unwrap();
+    /// assert_eq!(date.succ(), Some(Date::new_num(2025, 1, 1).unwrap()));
+    ///
+    /// assert_eq!(Date::MAX.succ(), None);
+    /// ```
+    #[inline]
+    pub fn succ(&self) -> Option<Self> {
+        self.add_days(1_i32).ok()
+    }
+
+    /// Returns the previous [`Date`].
+    ///
+    /// Unlike [`Date::add_days`] this returns [`None`] at [`Date::MIN`] instead of an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 3, 1).unwrap();
+    /// assert_eq!(date.pred(), Some(Date::new_num(2024, 2, 29).unwrap()));
+    ///
+    /// assert_eq!(Date::MIN.pred(), None);
+    /// ```
+    #[inline]
+    pub fn pred(&self) -> Option<Self> {
+        self.add_days(-1_i32).ok()
+    }
+
+    /// Returns the number of days since 00.01.0000.
+    ///
+    /// So 01.01.0001 is day 1.
+    /// This method is formula-based and leap-year safe.
+    ///
+    /// See [`Date::from_days`] for the reverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date_1: Date = Date::new_num(1970, 1, 1).unwrap();
+    /// assert_eq!(date_1.to_days(), 719_163);
+    ///
+    /// let date_2: Date = Date::new_num(1970, 3, 1).unwrap();
+    /// assert_eq!(date_2.to_days() - date_1.to_days(), 59);
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    #[inline]
+    pub fn to_days(&self) -> i32 {
+        let mut days: i32 = Self::days_before_year(self.year.value());
+
+        // Cumulative days in months (non-leap by default)
+        const MONTH_DAYS: [i32; 13] = [0, 0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+        days += MONTH_DAYS[self.month as usize];
+
+        // Add current day
+        days += i32::from(self.day);
+
+        // Leap year adjustment
+        if self.month > Month::February && self.year.is_leap_year() {
+            days += 1;
+        }
+
+        days
+    }
+
+    /// Returns the number of days in all full years before `year` since 00.01.0000.
+    ///
+    /// This takes leap years into account and works for any `year`.
+    #[inline]
+    const fn days_before_year(year: i32) -> i32 {
+        let full_years: i32 = year - 1;
+
+        // Days in previous full years with leaps
+        full_years * 365 + full_years / 4 - full_years / 100 + full_years / 400
+    }
+
+    /// Creates a new [`Date`] instance from the number of days since 00.01.0000.
+    ///
+    /// This is the inverse of [`Date::to_days`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - The resulting year is not between [`Year::MIN`] and [`Year::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date};
+    /// // Valid
+    /// let date: Date = Date::from_days(719_163).unwrap();
+    /// assert_eq!(date, Date::new_num(1970, 1, 1).unwrap());
+    ///
+    /// // Round trip for every valid date
+    /// for days in Date::MIN.to_days()..=Date::MAX.to_days() {
+    ///     assert_eq!(Date::from_days(days).unwrap().to_days(), days);
+    /// }
+    ///
+    /// // YearError
+    /// let year_error: ChronoError = Date::from_days(Date::MIN.to_days() - 1).err().unwrap();
+    /// assert_eq!(year_error, ChronoError::YearError(1899));
+    ///
+    /// let year_error: ChronoError = Date::from_days(Date::MAX.to_days() + 1).err().unwrap();
+    /// assert_eq!(year_error, ChronoError::YearError(2101));
+    /// ```
+    pub fn from_days(days: i32) -> Result<Self, ChronoError> {
+        // Shift to days since 01.03.0000, so that leap days are at the end of a year
+        let shifted: i64 = days as i64 + 305;
+
+        // 400 year cycles with 146.097 days each
+        let era: i64 = shifted.div_euclid(146_097);
+        let day_of_era: i64 = shifted.rem_euclid(146_097);
+        let year_of_era: i64 =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year: i64 =
+            day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+
+        // Months starting in march
+        let shifted_month: i64 = (5 * day_of_year + 2) / 153;
+        let day_u8: u8 = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+        let month_u8: u8 = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u8;
+        let year_i32: i32 = (year_of_era + era * 400 + (month_u8 <= 2) as i64) as i32;
+
+        let year: Year = Year::new(year_i32)?;
+        let month: Month = Month::new(month_u8)?; // Unfailable
+        let day: Day = Day::new(day_u8, month, year)?; // Unfailable
+
+        Ok(Self { year, month, day })
+    }
+
+    /// The result of [`Date::to_days`] for 01.01.1970.
+    const UNIX_EPOCH_DAYS: i32 = 719_163;
+
+    /// The number of seconds in a day.
+    const SECONDS_PER_DAY: i64 = 86_400;
+
+    /// The result of [`Date::to_days`] for 30.12.1899, which is day `0` of [`Epoch::Excel1900`]
+    /// from 01.03.1900 onwards.
+    const EXCEL_EPOCH_DAYS: i32 = 693_594;
+
+    /// The first serial number of [`Epoch::Excel1900`] after the non-existent 29.02.1900.
+    const EXCEL_LEAP_BUG_SERIAL: i32 = 60;
+
+    /// Returns the Unix timestamp of a [`Date`].
+    ///
+    /// This is the number of seconds since 01.01.1970 at midnight UTC of the [`Date`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(1970, 1, 1).unwrap();
+    /// assert_eq!(date.to_unix_timestamp(), 0);
+    ///
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.to_unix_timestamp(), 1_717_200_000);
+    ///
+    /// let date: Date = Date::new_num(1969, 12, 31).unwrap();
+    /// assert_eq!(date.to_unix_timestamp(), -86_400);
+    /// ```
+    #[inline]
+    pub fn to_unix_timestamp(&self) -> i64 {
+        (self.to_days() - Self::UNIX_EPOCH_DAYS) as i64 * Self::SECONDS_PER_DAY
+    }
+
+    /// Creates a new [`Date`] instance from a Unix timestamp.
+    ///
+    /// The `seconds` are interpreted as UTC.
+    /// Any time of day is discarded, so the result is the [`Date`] containing the timestamp.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - The resulting year is not between [`Year::MIN`] and [`Year::MAX`].
+    /// * [`ChronoError::OverflowError`] - The `seconds` argument was too large.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date};
+    /// // Valid
+    /// let date: Date = Date::from_unix_timestamp(0).unwrap();
+    /// assert_eq!(date, Date::new_num(1970, 1, 1).unwrap());
+    ///
+    /// // Time of day is discarded
+    /// let date: Date = Date::from_unix_timestamp(1_717_243_199).unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 6, 1).unwrap());
+    ///
+    /// let date: Date = Date::from_unix_timestamp(-1).unwrap();
+    /// assert_eq!(date, Date::new_num(1969, 12, 31).unwrap());
+    ///
+    /// // Round trip
+    /// let date: Date = Date::new_num(2024, 2, 29).unwrap();
+    /// assert_eq!(Date::from_unix_timestamp(date.to_unix_timestamp()).unwrap(), date);
+    ///
+    /// // YearError
+    /// let year_error: ChronoError = Date::from_unix_timestamp(5_000_000_000).err().unwrap();
+    /// assert_eq!(year_error, ChronoError::YearError(2128));
+    ///
+    /// // OverflowError
+    /// let overflow_error: ChronoError = Date::from_unix_timestamp(i64::MAX).err().unwrap();
+    /// assert_eq!(overflow_error, ChronoError::OverflowError);
+    /// ```
+    pub fn from_unix_timestamp(seconds: i64) -> Result<Self, ChronoError> {
+        let days: i32 = seconds
+            .div_euclid(Self::SECONDS_PER_DAY)
+            .checked_add(Self::UNIX_EPOCH_DAYS as i64)
+            .ok_or(ChronoError::OverflowError)?
+            .try_into()
+            .map_err(|_| ChronoError::OverflowError)?;
+
+        Self::from_days(days)
+    }
+
+    /// Creates a new [`Date`] instance of the current day.
+    ///
+    /// The current time is read from [`std::time::SystemTime::now`] and interpreted as UTC,
+    /// so around midnight this may differ from the local date.
+    ///
+    /// This is only available with the `std-clock` feature.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - The current year is not between [`Year::MIN`] and [`Year::MAX`].
+    /// * [`ChronoError::OverflowError`] - The system time is too far away from the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let today: Date = Date::today().unwrap();
+    /// assert!(today >= Date::new_num(2024, 1, 1).unwrap());
+    /// ```
+    #[cfg(feature = "std-clock")]
+    pub fn today() -> Result<Self, ChronoError> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let seconds: i64 = match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration
+                .as_secs()
+                .try_into()
+                .map_err(|_| ChronoError::OverflowError)?,
+            Err(error) => {
+                // Before the epoch any fraction of a second belongs to the previous second
+                let duration: std::time::Duration = error.duration();
+                let seconds: i64 = duration
+                    .as_secs()
+                    .try_into()
+                    .map_err(|_| ChronoError::OverflowError)?;
+                -seconds - i64::from(duration.subsec_nanos() > 0)
+            }
+        };
+
+        Self::from_unix_timestamp(seconds)
+    }
+
+    /// Returns the number of days since a given [`Epoch`].
+    ///
+    /// # Notes
+    ///
+    /// For [`Epoch::Excel1900`] the leap year bug of Excel is reproduced.
+    /// So any [`Date`] from 01.03.1900 onwards is one day later than the true count.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Epoch};
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.days_since(Epoch::Unix1970), 19_875);
+    /// assert_eq!(date.days_since(Epoch::Excel1900), 45_444);
+    /// assert_eq!(date.days_since(Epoch::RataDie), 739_038);
+    ///
+    /// // Before the Excel leap year bug
+    /// let date: Date = Date::new_num(1900, 2, 28).unwrap();
+    /// assert_eq!(date.days_since(Epoch::Excel1900), 59);
+    ///
+    /// // After the Excel leap year bug
+    /// let date: Date = Date::new_num(1900, 3, 1).unwrap();
+    /// assert_eq!(date.days_since(Epoch::Excel1900), 61);
+    /// ```
+    pub fn days_since(&self, epoch: Epoch) -> i64 {
+        let days: i32 = self.to_days();
+
+        let days_since: i32 = match epoch {
+            Epoch::Unix1970 => days - Self::UNIX_EPOCH_DAYS,
+            Epoch::Excel1900 => {
+                let serial: i32 = days - Self::EXCEL_EPOCH_DAYS;
+                if serial > Self::EXCEL_LEAP_BUG_SERIAL {
+                    serial
+                } else {
+                    // Before 01.03.1900 there is no bug yet
+                    serial - 1
+                }
+            }
+            Epoch::RataDie => days,
+        };
+
+        days_since as i64
+    }
+
+    /// Creates a new [`Date`] instance from a number of days since a given [`Epoch`].
+    ///
+    /// This is the inverse of [`Date::days_since`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - The resulting year is not between [`Year::MIN`] and [`Year::MAX`].
+    /// * [`ChronoError::DayError`] - The serial number `60` of [`Epoch::Excel1900`] is the non-existent 29.02.1900.
+    /// * [`ChronoError::OverflowError`] - The `days` argument was too large.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date, Epoch};
+    /// // Valid
+    /// let date: Date = Date::from_days_since(19_875, Epoch::Unix1970).unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 6, 1).unwrap());
+    ///
+    /// let date: Date = Date::from_days_since(45_444, Epoch::Excel1900).unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 6, 1).unwrap());
+    ///
+    /// let date: Date = Date::from_days_since(1, Epoch::Excel1900).unwrap();
+    /// assert_eq!(date, Date::new_num(1900, 1, 1).unwrap());
+    ///
+    /// // DayError
+    /// let day_error: ChronoError = Date::from_days_since(60, Epoch::Excel1900).err().unwrap();
+    /// assert_eq!(day_error, ChronoError::DayError { day: 29, days_in_month: 28 });
+    ///
+    /// // YearError
+    /// let year_error: ChronoError = Date::from_days_since(0, Epoch::RataDie).err().unwrap();
+    /// assert_eq!(year_error, ChronoError::YearError(0));
+    ///
+    /// // OverflowError
+    /// let overflow_error: ChronoError = Date::from_days_since(i64::MAX, Epoch::Unix1970).err().unwrap();
+    /// assert_eq!(overflow_error, ChronoError::OverflowError);
+    /// ```
+    pub fn from_days_since(days: i64, epoch: Epoch) -> Result<Self, ChronoError> {
+        let days: i32 = days.try_into().map_err(|_| ChronoError::OverflowError)?;
+
+        let days: i32 = match epoch {
+            Epoch::Unix1970 => days.checked_add(Self::UNIX_EPOCH_DAYS),
+            Epoch::Excel1900 => match days {
+                Self::EXCEL_LEAP_BUG_SERIAL => {
+                    return Err(ChronoError::DayError {
+                        day: 29,
+                        days_in_month: 28,
+                    });
+                }
+                // Before 01.03.1900 there is no bug yet
+                ..Self::EXCEL_LEAP_BUG_SERIAL => days.checked_add(Self::EXCEL_EPOCH_DAYS + 1),
+                _ => days.checked_add(Self::EXCEL_EPOCH_DAYS),
+            },
+            Epoch::RataDie => Some(days),
+        }
+        .ok_or(ChronoError::OverflowError)?;
+
+        Self::from_days(days)
+    }
+
+    /// Returns the [`Date`] packed into a sortable integer 'yyyymmdd'.
+    ///
+    /// This is `year * 10_000 + month * 100 + day`.
+    /// The packed integers sort identically to the [`Date`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.to_sortable_i32(), 20_240_601);
+    ///
+    /// // Same order
+    /// let mut dates: Vec<Date> = vec![
+    ///     Date::new_num(2024, 6, 1).unwrap(),
+    ///     Date::new_num(1999, 12, 31).unwrap(),
+    ///     Date::new_num(2024, 1, 15).unwrap(),
+    ///     Date::new_num(2000, 1, 1).unwrap(),
+    /// ];
+    /// let mut keys: Vec<i32> = dates.iter().map(Date::to_sortable_i32).collect();
+    /// dates.sort();
+    /// keys.sort();
+    /// assert_eq!(keys, dates.iter().map(Date::to_sortable_i32).collect::<Vec<i32>>());
+    /// ```
+    #[inline]
+    pub fn to_sortable_i32(&self) -> i32 {
+        self.year.value() * 10_000_i32
+            + i32::from(self.month.value()) * 100_i32
+            + i32::from(self.day.value())
+    }
+
+    /// Creates a new [`Date`] instance from a sortable integer 'yyyymmdd'.
+    ///
+    /// This is the inverse of [`Date::to_sortable_i32`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - The `year` is not between [`Year::MIN`] and [`Year::MAX`] both included.
+    /// * [`ChronoError::MonthError`] - The `month` is not inside the interval [1, 12].
+    /// * [`ChronoError::DayError`] - The `month` of the `year` does not have the amount of days provided.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date};
+    /// // Valid
+    /// let date: Date = Date::from_sortable_i32(20_240_601).unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 6, 1).unwrap());
+    ///
+    /// // YearError
+    /// let year_error: ChronoError = Date::from_sortable_i32(240_601).err().unwrap();
+    /// assert_eq!(year_error, ChronoError::YearError(24));
+    ///
+    /// // MonthError
+    /// let month_error: ChronoError = Date::from_sortable_i32(20_241_301).err().unwrap();
+    /// assert_eq!(month_error, ChronoError::MonthError(13));
+    ///
+    /// // DayError
+    /// let day_error: ChronoError = Date::from_sortable_i32(20_230_229).err().unwrap();
+    /// assert_eq!(day_error, ChronoError::DayError { day: 29, days_in_month: 28 });
+    /// ```
+    pub fn from_sortable_i32(n: i32) -> Result<Self, ChronoError> {
+        let year: Year = Year::new(n / 10_000_i32)?;
+        let month: Month = Month::new((n / 100_i32).rem_euclid(100_i32) as u8)?;
+        let day: Day = Day::new(n.rem_euclid(100_i32) as u8, month, year)?;
+
+        Ok(Self { year, month, day })
+    }
+
+    /// Calculates the difference in days between two [`Date`]s.
+    ///
+    /// This is always a positive number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date_1: Date = Date::new_num(2024, 12, 31).unwrap();
+    /// let date_2: Date = Date::new_num(2024, 12, 31).unwrap();
+    /// assert_eq!(date_1.day_difference(&date_2), 0);
+    /// assert_eq!(date_2.day_difference(&date_1), 0);
+    ///
+    /// let date_1: Date = Date::new_num(2024, 12, 31).unwrap();
+    /// let date_2: Date = Date::new_num(2024, 12, 20).unwrap();
+    /// assert_eq!(date_1.day_difference(&date_2), 11);
     ///
     /// let date_1: Date = Date::new_num(2004, 6, 12).unwrap();
     /// let date_2: Date = Date::new_num(2001, 5, 9).unwrap();
@@ -551,12 +1889,7 @@ impl Date {
     /// assert_eq!(date_1.month_difference(&date_2, Rounding::Floor), 1);
     /// ```
     pub fn month_difference(&self, other: &Date, rounding: Rounding) -> i32 {
-        // Sorts `Date`s correctly
-        let (first, last): (&Date, &Date) = if self < other {
-            (self, other)
-        } else {
-            (other, self)
-        };
+        let (first, last): (Date, Date) = Self::ordered(*self, *other);
 
         let mut floor_diff: i32 = (last.year.value() - first.year.value()) * 12_i32
             + (last.month as i32 - first.month as i32);
@@ -609,17 +1942,11 @@ impl Date {
     /// assert_eq!(date_1.year_difference(&date_2, Rounding::Floor), 4);
     /// ```
     pub fn year_difference(&self, other: &Date, rounding: Rounding) -> i32 {
-        // Sorts `Date`s correctly
-        let (first, last): (&Date, &Date) = if self < other {
-            (self, other)
-        } else {
-            (other, self)
-        };
+        let (first, last): (Date, Date) = Self::ordered(*self, *other);
 
         let mut floor_diff: i32 = last.year.value() - first.year.value();
 
-        // Lexicographical comparison
-        if (last.month, last.day) < (first.month, first.day) {
+        if !first.has_had_birthday_this_year(&last) {
             floor_diff -= 1_i32;
         }
 
@@ -633,7 +1960,7 @@ impl Date {
                 }
             }
             Rounding::Nearest => {
-                let month_diff: i32 = (last.month as i32 - first.month as i32 + 12) % 12;
+                let month_diff: i32 = i32::from(first.month.distance_forward(&last.month));
 
                 if month_diff > 6 {
                     // More than 6 months
@@ -656,25 +1983,159 @@ impl Date {
         }
     }
 
-    /// Calculates the actuarial [`Age`] of a person.
+    /// Calculates the difference between two [`Date`]s based on a given [`Accuracy`] and [`Rounding`].
     ///
-    /// This is calculated by getting the effective date plus six month and calculating the [`Date::year_difference`].
+    /// This uses [`Date::day_difference`], [`Date::month_difference`] or [`Date::year_difference`].
+    /// The [`Rounding`] is ignored for [`Accuracy::DayExact`].
     ///
-    /// # Errors
+    /// This is always a positive number.
     ///
-    /// * [`ChronoError::AgeError`] - The resulting age would be outside the range of [`Age::MIN`] and [`Age::MAX`].
+    /// # Examples
     ///
     /// ```rust
-    /// # use date::Date;
-    /// let date_1: Date = Date::new_num(1959, 12, 31).unwrap();
-    /// let date_2: Date = Date::new_num(2024, 12, 31).unwrap();
-    /// assert_eq!(date_1.actuarial_age(&date_2).unwrap().value(), 65);
+    /// # use date::{Accuracy, Date, Rounding};
+    /// let date_1: Date = Date::new_num(2020, 1, 1).unwrap();
+    /// let date_2: Date = Date::new_num(2025, 12, 31).unwrap();
+    /// assert_eq!(date_1.difference_in(&date_2, Accuracy::DayExact, Rounding::Floor), 2_191);
+    /// assert_eq!(date_1.difference_in(&date_2, Accuracy::MonthExact, Rounding::Floor), 71);
+    /// assert_eq!(date_1.difference_in(&date_2, Accuracy::YearExact, Rounding::Floor), 5);
+    /// ```
+    #[inline]
+    pub fn difference_in(&self, other: &Date, accuracy: Accuracy, rounding: Rounding) -> i32 {
+        match accuracy {
+            Accuracy::DayExact => self.day_difference(other),
+            Accuracy::MonthExact => self.month_difference(other, rounding),
+            Accuracy::YearExact => self.year_difference(other, rounding),
+        }
+    }
+
+    /// Returns the [`Date::difference_in`] with its unit, e.g. "71 months".
     ///
-    /// let date_1: Date = Date::new_num(2001, 11, 5).unwrap();
-    /// let date_2: Date = Date::new_num(2024, 12, 31).unwrap();
-    /// assert_eq!(date_1.actuarial_age(&date_2).unwrap().value(), 23);
+    /// # Examples
     ///
-    /// let date_1: Date = Date::new_num(1959, 2, 12).unwrap();
+    /// ```rust
+    /// # use date::{Accuracy, Date, Rounding};
+    /// let date_1: Date = Date::new_num(2020, 1, 1).unwrap();
+    /// let date_2: Date = Date::new_num(2025, 12, 31).unwrap();
+    /// assert_eq!(date_1.difference_label(&date_2, Accuracy::MonthExact, Rounding::Floor), "71 months");
+    /// assert_eq!(date_1.difference_label(&date_2, Accuracy::YearExact, Rounding::Floor), "5 years");
+    ///
+    /// // Singular
+    /// let date_2: Date = Date::new_num(2021, 1, 1).unwrap();
+    /// assert_eq!(date_1.difference_label(&date_2, Accuracy::YearExact, Rounding::Floor), "1 year");
+    /// ```
+    pub fn difference_label(&self, other: &Date, accuracy: Accuracy, rounding: Rounding) -> String {
+        let difference: i32 = self.difference_in(other, accuracy, rounding);
+        let unit: &str = match accuracy {
+            Accuracy::DayExact => "day",
+            Accuracy::MonthExact => "month",
+            Accuracy::YearExact => "year",
+        };
+
+        format!(
+            "{} {}{}",
+            difference,
+            unit,
+            if difference == 1 { "" } else { "s" }
+        )
+    }
+
+    /// Returns a description of the [`Date`] relative to a `reference` in the given [`Language`].
+    ///
+    /// The largest sensible unit is used:
+    ///
+    /// * Days, if less than a full month lies in between.
+    /// * Months, if less than a full year lies in between.
+    /// * Years otherwise.
+    ///
+    /// The full units are based on [`Date::month_difference`] and [`Date::year_difference`] using [`Rounding::Floor`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Language};
+    /// let reference: Date = Date::new_num(2024, 6, 1).unwrap();
+    ///
+    /// // Same day
+    /// assert_eq!(reference.format_relative(&reference, Language::English), "today");
+    /// assert_eq!(reference.format_relative(&reference, Language::German), "heute");
+    ///
+    /// // Future days
+    /// let date: Date = Date::new_num(2024, 6, 4).unwrap();
+    /// assert_eq!(date.format_relative(&reference, Language::English), "in 3 days");
+    /// assert_eq!(date.format_relative(&reference, Language::German), "in 3 Tagen");
+    ///
+    /// // Past months
+    /// let date: Date = Date::new_num(2024, 3, 20).unwrap();
+    /// assert_eq!(date.format_relative(&reference, Language::English), "2 months ago");
+    /// assert_eq!(date.format_relative(&reference, Language::German), "vor 2 Monaten");
+    ///
+    /// // Singular
+    /// let date: Date = Date::new_num(2025, 6, 1).unwrap();
+    /// assert_eq!(date.format_relative(&reference, Language::English), "in 1 year");
+    /// assert_eq!(date.format_relative(&reference, Language::German), "in 1 Jahr");
+    /// ```
+    pub fn format_relative(&self, reference: &Date, language: Language) -> String {
+        let days: i32 = self.day_difference(reference);
+        let months: i32 = self.month_difference(reference, Rounding::Floor);
+        let years: i32 = self.year_difference(reference, Rounding::Floor);
+
+        let (amount, unit_en, unit_de_singular, unit_de_plural): (i32, &str, &str, &str) =
+            if months == 0 {
+                (days, "day", "Tag", "Tagen")
+            } else if years == 0 {
+                (months, "month", "Monat", "Monaten")
+            } else {
+                (years, "year", "Jahr", "Jahren")
+            };
+
+        match language {
+            Language::English => {
+                let plural: &str = if amount == 1 { "" } else { "s" };
+                if days == 0 {
+                    String::from("today")
+                } else if self > reference {
+                    format!("in {} {}{}", amount, unit_en, plural)
+                } else {
+                    format!("{} {}{} ago", amount, unit_en, plural)
+                }
+            }
+            Language::German => {
+                let unit: &str = if amount == 1 {
+                    unit_de_singular
+                } else {
+                    unit_de_plural
+                };
+                if days == 0 {
+                    String::from("heute")
+                } else if self > reference {
+                    format!("in {} {}", amount, unit)
+                } else {
+                    format!("vor {} {}", amount, unit)
+                }
+            }
+        }
+    }
+
+    /// Calculates the actuarial [`Age`] of a person.
+    ///
+    /// This is calculated by getting the effective date plus six month and calculating the [`Date::year_difference`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::AgeError`] - The resulting age would be outside the range of [`Age::MIN`] and [`Age::MAX`].
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date_1: Date = Date::new_num(1959, 12, 31).unwrap();
+    /// let date_2: Date = Date::new_num(2024, 12, 31).unwrap();
+    /// assert_eq!(date_1.actuarial_age(&date_2).unwrap().value(), 65);
+    ///
+    /// let date_1: Date = Date::new_num(2001, 11, 5).unwrap();
+    /// let date_2: Date = Date::new_num(2024, 12, 31).unwrap();
+    /// assert_eq!(date_1.actuarial_age(&date_2).unwrap().value(), 23);
+    ///
+    /// let date_1: Date = Date::new_num(1959, 2, 12).unwrap();
     /// let date_2: Date = Date::new_num(2024, 12, 31).unwrap();
     /// assert_eq!(date_1.actuarial_age(&date_2).unwrap().value(), 66);
     ///
@@ -737,31 +2198,1281 @@ impl Date {
     pub fn civil_age(&self, effective_date: &Date) -> Result<Age, ChronoError> {
         Age::try_from(self.year_difference(effective_date, Rounding::Floor))
     }
-}
 
-impl Display for Date {
-    fn fmt(&self, format: &mut Formatter) -> fmt::Result {
-        write!(format, "{}", self.format_dmy())
+    /// Validates a birth date by its civil [`Age`] on the date `as_of`.
+    ///
+    /// The birth date is returned, if the age is within the `bounds` both included.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::AgeError`] - The age is outside of the `bounds`.
+    ///   A birth date after `as_of` is always invalid and reported with an age of `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Age, ChronoError, Date};
+    /// let as_of: Date = Date::new_num(2025, 1, 1).unwrap();
+    /// let bounds: (Age, Age) = (Age::new(16).unwrap(), Age::new(100).unwrap());
+    ///
+    /// // Valid
+    /// let birth_date: Date = Date::new_num(1990, 6, 15).unwrap();
+    /// assert_eq!(birth_date.validate_birthdate(&as_of, bounds), Ok(birth_date));
+    ///
+    /// // AgeError (Too old)
+    /// let birth_date: Date = Date::new_num(1910, 6, 15).unwrap();
+    /// let age_error: ChronoError = birth_date.validate_birthdate(&as_of, bounds).err().unwrap();
+    /// assert_eq!(age_error, ChronoError::AgeError(114));
+    ///
+    /// // AgeError (In the future)
+    /// let birth_date: Date = Date::new_num(2030, 6, 15).unwrap();
+    /// let age_error: ChronoError = birth_date.validate_birthdate(&as_of, bounds).err().unwrap();
+    /// assert_eq!(age_error, ChronoError::AgeError(0));
+    /// ```
+    pub fn validate_birthdate(
+        &self,
+        as_of: &Date,
+        bounds: (Age, Age),
+    ) -> Result<Self, ChronoError> {
+        if self > as_of {
+            return Err(ChronoError::AgeError(0_u8));
+        }
+
+        // At most 200 years between Year::MIN and Year::MAX
+        let age: u8 = self
+            .year_difference(as_of, Rounding::Floor)
+            .try_into()
+            .unwrap_or(u8::MAX);
+
+        if age >= bounds.0.value() && age <= bounds.1.value() {
+            Ok(*self)
+        } else {
+            Err(ChronoError::AgeError(age))
+        }
     }
-}
 
-impl From<Year> for String {
-    fn from(year: Year) -> String {
-        format!("{}", year)
+    /// Returns the [`Date`] on which a person born on `self` reaches a given [`Age`].
+    ///
+    /// This is the inverse of [`Date::civil_age`].
+    /// For a birth on 29.02. the [`Age`] is reached on 28.02. in years which are not leap years,
+    /// as [`Date::add_years`] clamps the day.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - The resulting year is not between [`Year::MIN`] and [`Year::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Age, ChronoError, Date, Year};
+    /// // Valid
+    /// let birth_date: Date = Date::new_num(1990, 6, 15).unwrap();
+    /// let date: Date = birth_date.date_of_age(Age::new(65).unwrap()).unwrap();
+    /// assert_eq!(date, Date::new_num(2055, 6, 15).unwrap());
+    /// assert_eq!(birth_date.civil_age(&date).unwrap().value(), 65);
+    ///
+    /// // Born on 29.02.
+    /// let birth_date: Date = Date::new_num(2024, 2, 29).unwrap();
+    /// let date: Date = birth_date.date_of_age(Age::new(1).unwrap()).unwrap();
+    /// assert_eq!(date, Date::new_num(2025, 2, 28).unwrap());
+    ///
+    /// // YearError
+    /// let birth_date: Date = Date::new_num(2090, 1, 1).unwrap();
+    /// let year_error: ChronoError = birth_date.date_of_age(Age::new(20).unwrap()).err().unwrap();
+    /// assert_eq!(year_error, ChronoError::YearError(Year::MAX + 10));
+    /// ```
+    #[inline]
+    pub fn date_of_age(&self, age: Age) -> Result<Self, ChronoError> {
+        self.add_years(i32::from(age.value()))
     }
-}
 
-impl Add<i32> for Date {
-    type Output = Date;
+    /// Returns the next anniversary of the [`Date`] after the `reference` as `(years, date)`.
+    ///
+    /// Only the anniversaries for the given `milestones` in years are considered.
+    /// An anniversary of the 29th of February is moved to the 28th of February in non leap years.
+    /// If all milestones are on or before the `reference` or after [`Date::MAX`], [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let entry_date: Date = Date::new_num(2020, 4, 1).unwrap();
+    /// let milestones: [u32; 3] = [1, 5, 10];
+    ///
+    /// // Between the 1st and 5th anniversary
+    /// let reference: Date = Date::new_num(2023, 6, 1).unwrap();
+    /// assert_eq!(
+    ///     entry_date.next_milestone(&reference, &milestones),
+    ///     Some((5, Date::new_num(2025, 4, 1).unwrap()))
+    /// );
+    ///
+    /// // All milestones are past
+    /// let reference: Date = Date::new_num(2030, 4, 1).unwrap();
+    /// assert_eq!(entry_date.next_milestone(&reference, &milestones), None);
+    /// ```
+    pub fn next_milestone(&self, reference: &Date, milestones: &[u32]) -> Option<(u32, Date)> {
+        milestones
+            .iter()
+            .filter_map(|&years| {
+                let months: i32 = i32::try_from(years).ok()?.checked_mul(12_i32)?;
+                let anniversary: Date = self.add_months(months).ok()?;
 
-    /// [`Add`]s a specific amount of `days` to a [`Date`].
+                (anniversary > *reference).then_some((years, anniversary))
+            })
+            .min_by_key(|&(_, anniversary)| anniversary)
+    }
+
+    /// Checks if the month and day of `self` have occurred on or before the month and day of
+    /// `reference` within its year.
     ///
-    /// Use negative numbers for subtraction.
+    /// The birthday itself counts as had.
+    /// This drives [`Date::year_difference`] and thereby [`Date::civil_age`].
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// Any error in [`Date::add_days`] will cause this method to panic.
-    fn add(self, days: i32) -> Self::Output {
-        self.add_days(days).unwrap()
+    /// ```rust
+    /// # use date::Date;
+    /// let birth_date: Date = Date::new_num(1990, 6, 15).unwrap();
+    ///
+    /// // On the birthday
+    /// let reference: Date = Date::new_num(2025, 6, 15).unwrap();
+    /// assert!(birth_date.has_had_birthday_this_year(&reference));
+    ///
+    /// // Before the birthday
+    /// let reference: Date = Date::new_num(2025, 6, 14).unwrap();
+    /// assert!(!birth_date.has_had_birthday_this_year(&reference));
+    ///
+    /// // After the birthday
+    /// let reference: Date = Date::new_num(2025, 12, 1).unwrap();
+    /// assert!(birth_date.has_had_birthday_this_year(&reference));
+    /// ```
+    #[inline]
+    pub fn has_had_birthday_this_year(&self, reference: &Date) -> bool {
+        // Lexicographical comparison
+        (self.month, self.day) <= (reference.month, reference.day)
+    }
+
+    /// Returns the next [`Date`] on or after `reference` with the given `month` and `day`.
+    ///
+    /// A `day` of 29 in [`Month::February`] falls on 28.02. in years which are not leap years.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::DayError`] - The `month` never has the amount of days provided.
+    /// * [`ChronoError::YearError`] - The next occurrence would be after [`Year::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date, Month, Year};
+    /// // Later this year
+    /// let reference: Date = Date::new_num(2024, 12, 12).unwrap();
+    /// let christmas: Date = Date::next_occurrence(&reference, Month::December, 25).unwrap();
+    /// assert_eq!(christmas, Date::new_num(2024, 12, 25).unwrap());
+    ///
+    /// // On the reference
+    /// let reference: Date = Date::new_num(2024, 12, 25).unwrap();
+    /// let christmas: Date = Date::next_occurrence(&reference, Month::December, 25).unwrap();
+    /// assert_eq!(christmas, reference);
+    ///
+    /// // Next year
+    /// let reference: Date = Date::new_num(2024, 12, 26).unwrap();
+    /// let christmas: Date = Date::next_occurrence(&reference, Month::December, 25).unwrap();
+    /// assert_eq!(christmas, Date::new_num(2025, 12, 25).unwrap());
+    ///
+    /// // 29.02. in a year which is not a leap year
+    /// let reference: Date = Date::new_num(2024, 3, 1).unwrap();
+    /// let leap_day: Date = Date::next_occurrence(&reference, Month::February, 29).unwrap();
+    /// assert_eq!(leap_day, Date::new_num(2025, 2, 28).unwrap());
+    ///
+    /// // DayError
+    /// let day_error: ChronoError = Date::next_occurrence(&reference, Month::April, 31).err().unwrap();
+    /// assert_eq!(day_error, ChronoError::DayError { day: 31, days_in_month: 30 });
+    ///
+    /// // YearError
+    /// let reference: Date = Date::new_num(Year::MAX, 12, 26).unwrap();
+    /// let year_error: ChronoError = Date::next_occurrence(&reference, Month::December, 25).err().unwrap();
+    /// assert_eq!(year_error, ChronoError::YearError(Year::MAX + 1));
+    /// ```
+    pub fn next_occurrence(reference: &Date, month: Month, day: u8) -> Result<Self, ChronoError> {
+        // Any leap year allows every possible day
+        let leap_year: Year = Year::new_unchecked(2000_i32);
+        Day::new(day, month, leap_year)?;
+
+        let occurrence_in = |year: Year| -> Self {
+            let day: Day = Day::new_unchecked(day.min(month.days_in_month(year)));
+
+            Self { year, month, day }
+        };
+
+        let occurrence: Date = occurrence_in(reference.year);
+        if occurrence >= *reference {
+            Ok(occurrence)
+        } else {
+            Ok(occurrence_in(reference.year.add_years(1_i32)?))
+        }
+    }
+
+    /// Returns the [`Weekday`] of a [`Date`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Weekday};
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.weekday(), Weekday::Saturday);
+    ///
+    /// let date: Date = Date::new_num(1900, 1, 1).unwrap();
+    /// assert_eq!(date.weekday(), Weekday::Monday);
+    /// ```
+    #[inline]
+    pub fn weekday(&self) -> Weekday {
+        // 01.01.0001 is a monday
+        Weekday::from_offset(self.to_days() - 1)
+    }
+
+    /// Returns the [`Weekday`] of the first day of the month of a [`Date`].
+    ///
+    /// See [`Date::begin_of_month`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Weekday};
+    /// let date: Date = Date::new_num(2024, 6, 12).unwrap();
+    /// assert_eq!(date.first_weekday_of_month(), Weekday::Saturday);
+    /// ```
+    #[inline]
+    pub fn first_weekday_of_month(&self) -> Weekday {
+        self.begin_of_month().weekday()
+    }
+
+    /// Returns the [`Weekday`] of the last day of the month of a [`Date`].
+    ///
+    /// See [`Date::end_of_month`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Weekday};
+    /// let date: Date = Date::new_num(2024, 6, 12).unwrap();
+    /// assert_eq!(date.last_weekday_of_month(), Weekday::Sunday);
+    /// ```
+    #[inline]
+    pub fn last_weekday_of_month(&self) -> Weekday {
+        self.end_of_month().weekday()
+    }
+
+    /// Returns the ISO 8601 week-numbering year and week number of a [`Date`].
+    ///
+    /// Week 1 is the week containing the first [`Weekday::Thursday`] of the year.
+    /// Weeks start on a [`Weekday::Monday`], so the first and last days of a year
+    /// may belong to a week of the previous or next week-numbering year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// // Regular
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.iso_week(), (2024, 22));
+    ///
+    /// // Belongs to the next year
+    /// let date: Date = Date::new_num(2024, 12, 30).unwrap();
+    /// assert_eq!(date.iso_week(), (2025, 1));
+    ///
+    /// // Belongs to the previous year
+    /// let date: Date = Date::new_num(2021, 1, 1).unwrap();
+    /// assert_eq!(date.iso_week(), (2020, 53));
+    /// ```
+    #[inline]
+    pub fn iso_week(&self) -> (i32, u8) {
+        // The thursday of the same week decides the year
+        let thursday: i32 = self.to_days() - i32::from(self.weekday()) + 4_i32;
+
+        let mut year: i32 = self.year.value();
+        if thursday <= Self::days_before_year(year) {
+            year -= 1;
+        } else if thursday > Self::days_before_year(year + 1) {
+            year += 1;
+        }
+
+        let week: i32 = (thursday - Self::days_before_year(year) - 1) / 7 + 1;
+
+        (year, week as u8)
+    }
+
+    /// Checks if two [`Date`]s are in the same month of the same year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date_1: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// let date_2: Date = Date::new_num(2024, 6, 30).unwrap();
+    /// assert!(date_1.is_same_month(&date_2));
+    ///
+    /// // Different year
+    /// let date_2: Date = Date::new_num(2025, 6, 1).unwrap();
+    /// assert!(!date_1.is_same_month(&date_2));
+    /// ```
+    #[inline]
+    pub fn is_same_month(&self, other: &Date) -> bool {
+        self.year == other.year && self.month == other.month
+    }
+
+    /// Checks if two [`Date`]s are in the same year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date_1: Date = Date::new_num(2024, 1, 1).unwrap();
+    /// let date_2: Date = Date::new_num(2024, 12, 31).unwrap();
+    /// assert!(date_1.is_same_year(&date_2));
+    ///
+    /// let date_2: Date = Date::new_num(2025, 1, 1).unwrap();
+    /// assert!(!date_1.is_same_year(&date_2));
+    /// ```
+    #[inline]
+    pub fn is_same_year(&self, other: &Date) -> bool {
+        self.year == other.year
+    }
+
+    /// Checks if two [`Date`]s are in the same ISO 8601 week.
+    ///
+    /// Both the week-numbering year and the week number have to match.
+    /// So dates in different calendar years can be in the same week.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// // Monday and Sunday of the same week
+    /// let date_1: Date = Date::new_num(2024, 6, 3).unwrap();
+    /// let date_2: Date = Date::new_num(2024, 6, 9).unwrap();
+    /// assert!(date_1.is_same_iso_week(&date_2));
+    ///
+    /// // Sunday and the following Monday
+    /// let date_1: Date = Date::new_num(2024, 6, 9).unwrap();
+    /// let date_2: Date = Date::new_num(2024, 6, 10).unwrap();
+    /// assert!(!date_1.is_same_iso_week(&date_2));
+    ///
+    /// // Same week across the turn of the year
+    /// let date_1: Date = Date::new_num(2024, 12, 31).unwrap();
+    /// let date_2: Date = Date::new_num(2025, 1, 1).unwrap();
+    /// assert!(date_1.is_same_iso_week(&date_2));
+    /// ```
+    #[inline]
+    pub fn is_same_iso_week(&self, other: &Date) -> bool {
+        self.iso_week() == other.iso_week()
+    }
+
+    /// Returns the number of days from `self` until the next given [`Weekday`].
+    ///
+    /// This is inside the interval [0, 6] and `0` if `self` already is the `target`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Weekday};
+    /// let date: Date = Date::new_num(2024, 6, 5).unwrap(); // Wednesday
+    /// assert_eq!(date.days_until_weekday(Weekday::Monday), 5);
+    /// assert_eq!(date.days_until_weekday(Weekday::Thursday), 1);
+    /// assert_eq!(date.days_until_weekday(Weekday::Wednesday), 0);
+    /// ```
+    #[inline]
+    pub fn days_until_weekday(&self, target: Weekday) -> u8 {
+        (i32::from(target) - i32::from(self.weekday())).rem_euclid(7) as u8
+    }
+
+    /// Returns the number of days since the last given [`Weekday`] up to `self`.
+    ///
+    /// This is inside the interval [0, 6] and `0` if `self` already is the `target`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Weekday};
+    /// let date: Date = Date::new_num(2024, 6, 5).unwrap(); // Wednesday
+    /// assert_eq!(date.days_since_weekday(Weekday::Monday), 2);
+    /// assert_eq!(date.days_since_weekday(Weekday::Thursday), 6);
+    /// assert_eq!(date.days_since_weekday(Weekday::Wednesday), 0);
+    /// ```
+    #[inline]
+    pub fn days_since_weekday(&self, target: Weekday) -> u8 {
+        (i32::from(self.weekday()) - i32::from(target)).rem_euclid(7) as u8
+    }
+
+    /// Returns the first [`Date`] on or after `self` with the given [`Weekday`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - Based on [`Date::add_days`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Weekday};
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap(); // Saturday
+    /// let monday: Date = date.weekday_on_or_after(Weekday::Monday).unwrap();
+    /// assert_eq!(monday, Date::new_num(2024, 6, 3).unwrap());
+    ///
+    /// let saturday: Date = date.weekday_on_or_after(Weekday::Saturday).unwrap();
+    /// assert_eq!(saturday, date);
+    /// ```
+    pub fn weekday_on_or_after(&self, weekday: Weekday) -> Result<Self, ChronoError> {
+        self.add_days(i32::from(self.days_until_weekday(weekday)))
+    }
+
+    /// Returns the last [`Date`] on or before `self` with the given [`Weekday`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - Based on [`Date::add_days`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Weekday};
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap(); // Saturday
+    /// let monday: Date = date.weekday_on_or_before(Weekday::Monday).unwrap();
+    /// assert_eq!(monday, Date::new_num(2024, 5, 27).unwrap());
+    ///
+    /// let saturday: Date = date.weekday_on_or_before(Weekday::Saturday).unwrap();
+    /// assert_eq!(saturday, date);
+    /// ```
+    pub fn weekday_on_or_before(&self, weekday: Weekday) -> Result<Self, ChronoError> {
+        self.add_days(-i32::from(self.days_since_weekday(weekday)))
+    }
+
+    /// Returns the first [`Date`] strictly after `self` with the given [`Weekday`].
+    ///
+    /// Unlike [`Date::weekday_on_or_after`] this never returns `self`.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - Based on [`Date::add_days`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Weekday};
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap(); // Saturday
+    /// let saturday: Date = date.next_weekday(Weekday::Saturday).unwrap();
+    /// assert_eq!(saturday, Date::new_num(2024, 6, 8).unwrap());
+    ///
+    /// // Crossing the year
+    /// let date: Date = Date::new_num(2024, 12, 28).unwrap(); // Saturday
+    /// let wednesday: Date = date.next_weekday(Weekday::Wednesday).unwrap();
+    /// assert_eq!(wednesday, Date::new_num(2025, 1, 1).unwrap());
+    /// ```
+    pub fn next_weekday(&self, weekday: Weekday) -> Result<Self, ChronoError> {
+        self.add_days(1_i32)?.weekday_on_or_after(weekday)
+    }
+
+    /// Returns the last [`Date`] strictly before `self` with the given [`Weekday`].
+    ///
+    /// Unlike [`Date::weekday_on_or_before`] this never returns `self`.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - Based on [`Date::add_days`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Weekday};
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap(); // Saturday
+    /// let saturday: Date = date.previous_weekday(Weekday::Saturday).unwrap();
+    /// assert_eq!(saturday, Date::new_num(2024, 5, 25).unwrap());
+    ///
+    /// // Crossing the year
+    /// let date: Date = Date::new_num(2025, 1, 2).unwrap(); // Thursday
+    /// let monday: Date = date.previous_weekday(Weekday::Monday).unwrap();
+    /// assert_eq!(monday, Date::new_num(2024, 12, 30).unwrap());
+    /// ```
+    pub fn previous_weekday(&self, weekday: Weekday) -> Result<Self, ChronoError> {
+        self.add_days(-1_i32)?.weekday_on_or_before(weekday)
+    }
+
+    /// Rounds a [`Date`] to the nearest [`Date`] with the given [`Weekday`].
+    ///
+    /// Unlike [`Date::weekday_on_or_after`] and [`Date::weekday_on_or_before`] this chooses
+    /// whichever occurrence is fewer days away.
+    /// As a week has an odd number of days, there is never a tie.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - The resulting year is not between [`Year::MIN`] and [`Year::MAX`].
+    ///   This can only happen within three days of the first or last supported [`Date`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Weekday};
+    /// // Wednesday rounds back
+    /// let date: Date = Date::new_num(2024, 6, 5).unwrap();
+    /// let monday: Date = date.round_to_nearest_weekday(Weekday::Monday).unwrap();
+    /// assert_eq!(monday, Date::new_num(2024, 6, 3).unwrap());
+    ///
+    /// // Thursday rounds back
+    /// let date: Date = Date::new_num(2024, 6, 6).unwrap();
+    /// let monday: Date = date.round_to_nearest_weekday(Weekday::Monday).unwrap();
+    /// assert_eq!(monday, Date::new_num(2024, 6, 3).unwrap());
+    ///
+    /// // Friday rounds forward
+    /// let date: Date = Date::new_num(2024, 6, 7).unwrap();
+    /// let monday: Date = date.round_to_nearest_weekday(Weekday::Monday).unwrap();
+    /// assert_eq!(monday, Date::new_num(2024, 6, 10).unwrap());
+    ///
+    /// // Already on the weekday
+    /// let date: Date = Date::new_num(2024, 6, 3).unwrap();
+    /// let monday: Date = date.round_to_nearest_weekday(Weekday::Monday).unwrap();
+    /// assert_eq!(monday, date);
+    /// ```
+    #[inline]
+    pub fn round_to_nearest_weekday(&self, weekday: Weekday) -> Result<Self, ChronoError> {
+        let days_after: i32 = i32::from(self.days_until_weekday(weekday));
+        let days_before: i32 = i32::from(self.days_since_weekday(weekday));
+
+        if days_after <= days_before {
+            self.add_days(days_after)
+        } else {
+            self.add_days(-days_before)
+        }
+    }
+
+    /// Counts the occurrences of a [`Weekday`] between two [`Date`]s.
+    ///
+    /// The interval is half-open, so the earlier [`Date`] is included and the later one is not.
+    /// The order of `self` and `other` does not matter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Weekday};
+    /// // Four full weeks starting on a monday
+    /// let date_1: Date = Date::new_num(2024, 1, 1).unwrap();
+    /// let date_2: Date = Date::new_num(2024, 1, 29).unwrap();
+    /// assert_eq!(date_1.count_weekday_between(&date_2, Weekday::Monday), 4);
+    /// assert_eq!(date_2.count_weekday_between(&date_1, Weekday::Monday), 4);
+    /// assert_eq!(date_1.count_weekday_between(&date_2, Weekday::Friday), 4);
+    ///
+    /// // Including the later date adds another monday
+    /// let date_2: Date = Date::new_num(2024, 1, 30).unwrap();
+    /// assert_eq!(date_1.count_weekday_between(&date_2, Weekday::Monday), 5);
+    ///
+    /// // Empty interval
+    /// assert_eq!(date_1.count_weekday_between(&date_1, Weekday::Monday), 0);
+    /// ```
+    pub fn count_weekday_between(&self, other: &Date, weekday: Weekday) -> u32 {
+        let (first, last): (Date, Date) = Self::ordered(*self, *other);
+        let offset: i32 = (i32::from(weekday) - i32::from(first.weekday())).rem_euclid(7);
+
+        // First occurrence on or after `first`
+        let first_days: i32 = first.to_days() + offset;
+        let last_days: i32 = last.to_days();
+
+        if first_days >= last_days {
+            0_u32
+        } else {
+            ((last_days - first_days - 1) / 7 + 1) as u32
+        }
+    }
+
+    /// Checks if a [`Date`] is a [`Weekday::Saturday`] or [`Weekday::Sunday`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// // Saturday
+    /// assert!(Date::new_num(2024, 6, 1).unwrap().is_weekend());
+    /// // Sunday
+    /// assert!(Date::new_num(2024, 6, 2).unwrap().is_weekend());
+    /// // Wednesday
+    /// assert!(!Date::new_num(2024, 6, 5).unwrap().is_weekend());
+    /// ```
+    #[inline]
+    pub fn is_weekend(&self) -> bool {
+        matches!(self.weekday(), Weekday::Saturday | Weekday::Sunday)
+    }
+
+    /// Checks if a [`Date`] is a [`Weekday::Monday`] to [`Weekday::Friday`].
+    ///
+    /// This is the negation of [`Date::is_weekend`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// // Saturday
+    /// assert!(!Date::new_num(2024, 6, 1).unwrap().is_weekday());
+    /// // Sunday
+    /// assert!(!Date::new_num(2024, 6, 2).unwrap().is_weekday());
+    /// // Wednesday
+    /// assert!(Date::new_num(2024, 6, 5).unwrap().is_weekday());
+    /// ```
+    #[inline]
+    pub fn is_weekday(&self) -> bool {
+        !self.is_weekend()
+    }
+
+    /// Returns the [`Date`] itself, if it is a business day, otherwise the next [`Weekday::Monday`].
+    ///
+    /// Business days are [`Weekday::Monday`] to [`Weekday::Friday`].
+    /// Holidays are not taken into account.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - Based on [`Date::add_days`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// // Saturday
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// let business_day: Date = date.next_business_day_inclusive().unwrap();
+    /// assert_eq!(business_day, Date::new_num(2024, 6, 3).unwrap());
+    ///
+    /// // Wednesday
+    /// let date: Date = Date::new_num(2024, 6, 5).unwrap();
+    /// let business_day: Date = date.next_business_day_inclusive().unwrap();
+    /// assert_eq!(business_day, date);
+    /// ```
+    pub fn next_business_day_inclusive(&self) -> Result<Self, ChronoError> {
+        if self.is_weekend() {
+            self.weekday_on_or_after(Weekday::Monday)
+        } else {
+            Ok(*self)
+        }
+    }
+
+    /// Returns the [`Date`] itself, if it is a business day, otherwise a business day based on a [`WeekendRule`].
+    ///
+    /// Business days are [`Weekday::Monday`] to [`Weekday::Friday`].
+    /// Holidays are not taken into account.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, WeekendRule};
+    /// // Wednesday
+    /// let date: Date = Date::new_num(2024, 6, 5).unwrap();
+    /// assert_eq!(date.to_nearest_business_day(WeekendRule::Following), date);
+    /// assert_eq!(date.to_nearest_business_day(WeekendRule::Preceding), date);
+    /// assert_eq!(date.to_nearest_business_day(WeekendRule::Nearest), date);
+    ///
+    /// // Sunday
+    /// let date: Date = Date::new_num(2024, 6, 2).unwrap();
+    /// assert_eq!(date.to_nearest_business_day(WeekendRule::Following), Date::new_num(2024, 6, 3).unwrap());
+    /// assert_eq!(date.to_nearest_business_day(WeekendRule::Preceding), Date::new_num(2024, 5, 31).unwrap());
+    /// assert_eq!(date.to_nearest_business_day(WeekendRule::Nearest), Date::new_num(2024, 6, 3).unwrap());
+    ///
+    /// // Saturday
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.to_nearest_business_day(WeekendRule::Nearest), Date::new_num(2024, 5, 31).unwrap());
+    /// ```
+    pub fn to_nearest_business_day(&self, rule: WeekendRule) -> Self {
+        if !self.is_weekend() {
+            return *self;
+        }
+
+        let business_day: Result<Date, ChronoError> = match (rule, self.weekday()) {
+            (WeekendRule::Following, _) | (WeekendRule::Nearest, Weekday::Sunday) => {
+                self.weekday_on_or_after(Weekday::Monday)
+            }
+            (WeekendRule::Preceding, _) | (WeekendRule::Nearest, _) => {
+                self.weekday_on_or_before(Weekday::Friday)
+            }
+        };
+
+        // Date::MIN is a monday and Date::MAX is a friday, so always valid
+        business_day.unwrap_or(*self)
+    }
+
+    /// Adds a number of business days to a [`Date`] instance.
+    ///
+    /// To subtract use a negative sign.
+    /// Business days are [`Weekday::Monday`] to [`Weekday::Friday`].
+    /// Holidays are not taken into account.
+    ///
+    /// The [`Date`] is moved one day at a time, skipping any weekend, until `days` business days have passed.
+    /// For 0 `days` the [`Date`] itself is returned, even on a weekend.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::YearError`] - Based on [`Date::add_days`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// // Friday + 1
+    /// let date: Date = Date::new_num(2024, 6, 7).unwrap();
+    /// assert_eq!(date.add_business_days(1).unwrap(), Date::new_num(2024, 6, 10).unwrap());
+    ///
+    /// // Monday - 1
+    /// let date: Date = Date::new_num(2024, 6, 10).unwrap();
+    /// assert_eq!(date.add_business_days(-1).unwrap(), Date::new_num(2024, 6, 7).unwrap());
+    ///
+    /// // Saturday + 0
+    /// let date: Date = Date::new_num(2024, 6, 8).unwrap();
+    /// assert_eq!(date.add_business_days(0).unwrap(), date);
+    /// ```
+    pub fn add_business_days(&self, days: i32) -> Result<Self, ChronoError> {
+        let step: i32 = days.signum();
+        let mut remaining: u32 = days.unsigned_abs();
+        let mut date: Date = *self;
+
+        while remaining > 0 {
+            date = date.add_days(step)?;
+            if !date.is_weekend() {
+                remaining -= 1;
+            }
+        }
+
+        Ok(date)
+    }
+
+    /// Returns an iterator over the business days from `start` (included) to `end` (excluded).
+    ///
+    /// Business days are [`Weekday::Monday`] to [`Weekday::Friday`].
+    /// Holidays are not taken into account.
+    /// If `start` is not before `end` the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Weekday};
+    /// // Monday to the next Monday
+    /// let start: Date = Date::new_num(2024, 6, 3).unwrap();
+    /// let end: Date = Date::new_num(2024, 6, 10).unwrap();
+    /// let business_days: Vec<Date> = Date::weekdays_between(start, end).collect();
+    /// assert_eq!(business_days.len(), 5);
+    /// assert_eq!(business_days.first(), Some(&start));
+    /// assert_eq!(business_days.last().unwrap().weekday(), Weekday::Friday);
+    ///
+    /// // Wrong order
+    /// assert_eq!(Date::weekdays_between(end, start).count(), 0);
+    /// ```
+    pub fn weekdays_between(start: Date, end: Date) -> impl Iterator<Item = Date> {
+        std::iter::successors(Some(start), Date::succ)
+            .take_while(move |date| *date < end)
+            .filter(|date| !date.is_weekend())
+    }
+
+    /// Returns the astronomical [`Season`] of a [`Date`].
+    ///
+    /// The seasons start on fixed approximate dates of the equinoxes and solstices:
+    ///
+    /// * [`Season::Spring`] - 20.03.
+    /// * [`Season::Summer`] - 21.06.
+    /// * [`Season::Autumn`] - 22.09.
+    /// * [`Season::Winter`] - 21.12.
+    ///
+    /// The actual astronomical events may vary by a day from year to year, which is not taken into account.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Season};
+    /// let date: Date = Date::new_num(2024, 3, 19).unwrap();
+    /// assert_eq!(date.astronomical_season(), Season::Winter);
+    ///
+    /// let date: Date = Date::new_num(2024, 3, 21).unwrap();
+    /// assert_eq!(date.astronomical_season(), Season::Spring);
+    ///
+    /// let date: Date = Date::new_num(2024, 12, 21).unwrap();
+    /// assert_eq!(date.astronomical_season(), Season::Winter);
+    /// ```
+    #[inline]
+    pub const fn astronomical_season(&self) -> Season {
+        match (self.month.value(), self.day.value()) {
+            (1..=2, _) | (3, ..20) => Season::Winter,
+            (3..=5, _) | (6, ..21) => Season::Spring,
+            (6..=8, _) | (9, ..22) => Season::Summer,
+            (9..=11, _) | (12, ..21) => Season::Autumn,
+            _ => Season::Winter,
+        }
+    }
+
+    /// Returns the english name of the [`Weekday`] of a [`Date`].
+    ///
+    /// See [`Weekday::name`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.weekday_name(), "Saturday");
+    /// ```
+    #[inline]
+    pub fn weekday_name(&self) -> &'static str {
+        self.weekday().name()
+    }
+
+    /// Returns the german name of the [`Weekday`] of a [`Date`].
+    ///
+    /// See [`Weekday::name_de`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// assert_eq!(date.weekday_name_de(), "Samstag");
+    /// ```
+    #[inline]
+    pub fn weekday_name_de(&self) -> &'static str {
+        self.weekday().name_de()
+    }
+
+    /// Returns the english name of the [`Month`] of a [`Date`].
+    ///
+    /// See [`Month::name`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 3, 1).unwrap();
+    /// assert_eq!(date.month_name(), "March");
+    /// ```
+    #[inline]
+    pub const fn month_name(&self) -> &'static str {
+        self.month.name()
+    }
+
+    /// Returns the german name of the [`Month`] of a [`Date`].
+    ///
+    /// See [`Month::name_de`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date: Date = Date::new_num(2024, 3, 1).unwrap();
+    /// assert_eq!(date.month_name_de(), "März");
+    /// ```
+    #[inline]
+    pub const fn month_name_de(&self) -> &'static str {
+        self.month.name_de()
+    }
+
+    /// Returns the [`Date`] at a given `fraction` between `start` and `end`.
+    ///
+    /// This interpolates linearly over the days between both [`Date`]s and rounds to the nearest day,
+    /// with halves rounding away from `start`.
+    /// The `fraction` is clamped into [0, 1], so `0.0` yields `start` and `1.0` yields `end`.
+    /// A `fraction` of [`f64::NAN`] is treated as `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let start: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// let end: Date = Date::new_num(2024, 6, 4).unwrap();
+    ///
+    /// assert_eq!(Date::interpolate(&start, &end, 0.0), start);
+    /// assert_eq!(Date::interpolate(&start, &end, 1.0), end);
+    ///
+    /// // 1.5 days are rounded to 2 days
+    /// assert_eq!(Date::interpolate(&start, &end, 0.5), Date::new_num(2024, 6, 3).unwrap());
+    ///
+    /// // Clamped
+    /// assert_eq!(Date::interpolate(&start, &end, 2.0), end);
+    /// assert_eq!(Date::interpolate(&start, &end, -1.0), start);
+    ///
+    /// // Reversed
+    /// assert_eq!(Date::interpolate(&end, &start, 0.5), Date::new_num(2024, 6, 2).unwrap());
+    /// ```
+    pub fn interpolate(start: &Date, end: &Date, fraction: f64) -> Self {
+        let fraction: f64 = if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        };
+
+        let start_days: i32 = start.to_days();
+        let offset: f64 = (end.to_days() - start_days) as f64 * fraction;
+
+        // Between `start` and `end`, so always valid
+        Self::from_days(start_days + offset.round() as i32).unwrap_or(*start)
+    }
+
+    /// Returns the [`Date`] halfway between two [`Date`]s by day count.
+    ///
+    /// For an odd number of days between both, this rounds toward the earlier [`Date`].
+    /// The order of `self` and `other` does not matter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// // Even number of days
+    /// let date_1: Date = Date::new_num(2024, 6, 1).unwrap();
+    /// let date_2: Date = Date::new_num(2024, 6, 3).unwrap();
+    /// assert_eq!(date_1.midpoint(&date_2), Date::new_num(2024, 6, 2).unwrap());
+    ///
+    /// // Odd number of days
+    /// let date_2: Date = Date::new_num(2024, 6, 4).unwrap();
+    /// assert_eq!(date_1.midpoint(&date_2), Date::new_num(2024, 6, 2).unwrap());
+    /// assert_eq!(date_2.midpoint(&date_1), Date::new_num(2024, 6, 2).unwrap());
+    /// ```
+    pub fn midpoint(&self, other: &Date) -> Self {
+        let (first, last): (Date, Date) = Self::ordered(*self, *other);
+        let first_days: i32 = first.to_days();
+
+        // Between `first` and `last`, so always valid
+        Self::from_days(first_days + (last.to_days() - first_days) / 2_i32).unwrap_or(first)
+    }
+
+    /// Splits the span from `start` to `end` into calendar months.
+    ///
+    /// Each period is a `(period_start, period_end)` pair, both included, clipped to the span.
+    /// If `start` is after `end` the result is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let start: Date = Date::new_num(2024, 1, 15).unwrap();
+    /// let end: Date = Date::new_num(2024, 3, 10).unwrap();
+    /// assert_eq!(
+    ///     Date::split_by_month(&start, &end),
+    ///     vec![
+    ///         (Date::new_num(2024, 1, 15).unwrap(), Date::new_num(2024, 1, 31).unwrap()),
+    ///         (Date::new_num(2024, 2, 1).unwrap(), Date::new_num(2024, 2, 29).unwrap()),
+    ///         (Date::new_num(2024, 3, 1).unwrap(), Date::new_num(2024, 3, 10).unwrap()),
+    ///     ]
+    /// );
+    ///
+    /// // Within a single month
+    /// let end: Date = Date::new_num(2024, 1, 20).unwrap();
+    /// assert_eq!(Date::split_by_month(&start, &end), vec![(start, end)]);
+    ///
+    /// // Wrong order
+    /// assert!(Date::split_by_month(&end, &start).is_empty());
+    /// ```
+    pub fn split_by_month(start: &Date, end: &Date) -> Vec<(Date, Date)> {
+        let mut periods: Vec<(Date, Date)> = Vec::new();
+        let mut period_start: Date = *start;
+
+        while period_start <= *end {
+            let period_end: Date = period_start.end_of_month().min(*end);
+            periods.push((period_start, period_end));
+
+            match period_end.succ() {
+                Some(next_start) => period_start = next_start,
+                None => break,
+            }
+        }
+
+        periods
+    }
+
+    /// Returns an iterator over the quarters of the span from `start` to `end`.
+    ///
+    /// Each item is a `(quarter_start, quarter_end)` pair, both included, clipped to the span.
+    /// If `start` is after `end` the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let start: Date = Date::new_num(2024, 2, 10).unwrap();
+    /// let end: Date = Date::new_num(2024, 8, 20).unwrap();
+    /// assert_eq!(
+    ///     Date::quarters_between(start, end).collect::<Vec<(Date, Date)>>(),
+    ///     vec![
+    ///         (Date::new_num(2024, 2, 10).unwrap(), Date::new_num(2024, 3, 31).unwrap()),
+    ///         (Date::new_num(2024, 4, 1).unwrap(), Date::new_num(2024, 6, 30).unwrap()),
+    ///         (Date::new_num(2024, 7, 1).unwrap(), Date::new_num(2024, 8, 20).unwrap()),
+    ///     ]
+    /// );
+    ///
+    /// // Wrong order
+    /// assert_eq!(Date::quarters_between(end, start).count(), 0);
+    /// ```
+    pub fn quarters_between(start: Date, end: Date) -> impl Iterator<Item = (Date, Date)> {
+        let mut period_start: Option<Date> = Some(start);
+
+        std::iter::from_fn(move || {
+            let current_start: Date = period_start.filter(|date| *date <= end)?;
+            let current_end: Date = current_start.end_of_quarter().min(end);
+            period_start = current_end.succ();
+
+            Some((current_start, current_end))
+        })
+    }
+
+    /// Returns the [`PeriodRelation`] of two periods `(start, end)`.
+    ///
+    /// Both the start and the end belong to a period.
+    /// The [`Date`]s of each period may be given in any order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, PeriodRelation};
+    /// let a: (Date, Date) = (Date::new_num(2020, 1, 1).unwrap(), Date::new_num(2020, 12, 31).unwrap());
+    ///
+    /// // Disjoint
+    /// let b: (Date, Date) = (Date::new_num(2021, 2, 1).unwrap(), Date::new_num(2021, 12, 31).unwrap());
+    /// assert_eq!(Date::relate_periods(a, b), PeriodRelation::Disjoint);
+    ///
+    /// // Touching
+    /// let b: (Date, Date) = (Date::new_num(2021, 1, 1).unwrap(), Date::new_num(2021, 12, 31).unwrap());
+    /// assert_eq!(Date::relate_periods(a, b), PeriodRelation::Touching);
+    /// assert_eq!(Date::relate_periods(b, a), PeriodRelation::Touching);
+    ///
+    /// // Overlapping
+    /// let b: (Date, Date) = (Date::new_num(2020, 12, 31).unwrap(), Date::new_num(2021, 12, 31).unwrap());
+    /// assert_eq!(Date::relate_periods(a, b), PeriodRelation::Overlapping);
+    ///
+    /// // Contains
+    /// let b: (Date, Date) = (Date::new_num(2020, 3, 1).unwrap(), Date::new_num(2020, 12, 31).unwrap());
+    /// assert_eq!(Date::relate_periods(a, b), PeriodRelation::Contains);
+    ///
+    /// // ContainedBy
+    /// assert_eq!(Date::relate_periods(b, a), PeriodRelation::ContainedBy);
+    ///
+    /// // Equal
+    /// let b: (Date, Date) = (Date::new_num(2020, 12, 31).unwrap(), Date::new_num(2020, 1, 1).unwrap());
+    /// assert_eq!(Date::relate_periods(a, b), PeriodRelation::Equal);
+    /// ```
+    pub fn relate_periods(a: (Date, Date), b: (Date, Date)) -> PeriodRelation {
+        let (a_start, a_end): (Date, Date) = Self::ordered(a.0, a.1);
+        let (b_start, b_end): (Date, Date) = Self::ordered(b.0, b.1);
+
+        if (a_start, a_end) == (b_start, b_end) {
+            PeriodRelation::Equal
+        } else if a_end < b_start || b_end < a_start {
+            let gap: i32 = if a_end < b_start {
+                a_end.day_difference(&b_start)
+            } else {
+                b_end.day_difference(&a_start)
+            };
+
+            if gap == 1 {
+                PeriodRelation::Touching
+            } else {
+                PeriodRelation::Disjoint
+            }
+        } else if a_start <= b_start && b_end <= a_end {
+            PeriodRelation::Contains
+        } else if b_start <= a_start && a_end <= b_end {
+            PeriodRelation::ContainedBy
+        } else {
+            PeriodRelation::Overlapping
+        }
+    }
+
+    /// Asserts that all formatters and their parsers reproduce the [`Date`].
+    ///
+    /// This checks [`Date::format_dmy`], [`Date::format_ymd`], [`Date::iso_format`], the compact 'ddmmyyyy' form of
+    /// [`Date::from_string`], [`Date::to_unix_timestamp`], [`Date::to_sortable_i32`], [`Date::format_long_de`]
+    /// and [`Date::to_yyyymmdd_string`] against their parsers.
+    ///
+    /// This is only available with the `debug` feature.
+    /// The crate's own test suite always runs it over every supported [`Date`].
+    ///
+    /// # Panics
+    ///
+    /// Any of the parsers does not reproduce the [`Date`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, Year};
+    /// // Leap day
+    /// Date::new_num(2024, 2, 29).unwrap().assert_format_round_trips();
+    ///
+    /// // Last supported day
+    /// Date::new_num(Year::MAX, 12, 31).unwrap().assert_format_round_trips();
+    /// ```
+    #[cfg(any(test, feature = "debug"))]
+    pub fn assert_format_round_trips(&self) {
+        let compact: String = format!(
+            "{:02}{:02}{}",
+            self.day.value(),
+            self.month.value(),
+            self.year.value()
+        );
+
+        let round_trips: [(&str, String, Result<Date, ChronoError>); 8] = [
+            (
+                "format_dmy",
+                self.format_dmy(),
+                Self::parse_with_format(&self.format_dmy(), "%d.%m.%Y"),
+            ),
+            (
+                "format_ymd",
+                self.format_ymd(),
+                Self::parse_with_format(&self.format_ymd(), "%Y.%m.%d"),
+            ),
+            (
+                "iso_format",
+                self.iso_format(),
+                Self::from_iso(&self.iso_format()),
+            ),
+            ("from_string", compact.clone(), Self::from_string(&compact)),
+            (
+                "to_unix_timestamp",
+                self.to_unix_timestamp().to_string(),
+                Self::from_unix_timestamp(self.to_unix_timestamp()),
+            ),
+            (
+                "to_sortable_i32",
+                self.to_sortable_i32().to_string(),
+                Self::from_sortable_i32(self.to_sortable_i32()),
+            ),
+            (
+                "format_long_de",
+                self.format_long_de(),
+                Self::from_long_de(&self.format_long_de()),
+            ),
+            (
+                "to_yyyymmdd_string",
+                self.to_yyyymmdd_string(),
+                Self::from_yyyymmdd(&self.to_yyyymmdd_string()),
+            ),
+        ];
+
+        for (name, formatted, parsed) in round_trips {
+            assert_eq!(
+                parsed,
+                Ok(*self),
+                "{} of {:?} does not round trip via {:?}",
+                name,
+                self,
+                formatted
+            );
+        }
+    }
+
+    /// Returns two [`Date`]s ordered as `(earlier, later)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let date_1: Date = Date::new_num(2024, 12, 31).unwrap();
+    /// let date_2: Date = Date::new_num(2020, 1, 1).unwrap();
+    /// assert_eq!(Date::ordered(date_1, date_2), (date_2, date_1));
+    /// assert_eq!(Date::ordered(date_2, date_1), (date_2, date_1));
+    /// ```
+    #[inline]
+    pub fn ordered(date_1: Date, date_2: Date) -> (Date, Date) {
+        if date_1 <= date_2 {
+            (date_1, date_2)
+        } else {
+            (date_2, date_1)
+        }
+    }
+
+    /// The smallest supported [`Date`].
+    ///
+    /// This is 01.01. of [`Year::MIN`].
+    pub const MIN: Date = Date::new_const(Year::MIN, 1, 1);
+
+    /// The largest supported [`Date`].
+    ///
+    /// This is 31.12. of [`Year::MAX`].
+    pub const MAX: Date = Date::new_const(Year::MAX, 12, 31);
+}
+
+impl Display for Date {
+    fn fmt(&self, format: &mut Formatter) -> fmt::Result {
+        write!(format, "{}", self.format_dmy())
+    }
+}
+
+impl FromStr for Date {
+    type Err = ChronoError;
+
+    /// Parses a [`Date`] from a string using [`Date::from_string`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{ChronoError, Date};
+    /// // Valid
+    /// let date: Date = "01012024".parse().unwrap();
+    /// assert_eq!(date, Date::new_num(2024, 1, 1).unwrap());
+    ///
+    /// // ParseError
+    /// let parse_error: ChronoError = "112024".parse::<Date>().err().unwrap();
+    /// assert_eq!(parse_error, ChronoError::ParseError);
+    /// ```
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::from_string(string)
+    }
+}
+
+impl From<Year> for String {
+    fn from(year: Year) -> String {
+        format!("{}", year)
+    }
+}
+
+impl Add<i32> for Date {
+    type Output = Date;
+
+    /// [`Add`]s a specific amount of `days` to a [`Date`].
+    ///
+    /// Use negative numbers for subtraction.
+    ///
+    /// # Panics
+    ///
+    /// Any error in [`Date::add_days`] will cause this method to panic.
+    fn add(self, days: i32) -> Self::Output {
+        self.add_days(days).unwrap()
+    }
+}
+
+impl Sub<Date> for Date {
+    type Output = i32;
+
+    /// [`Sub`]tracts two [`Date`]s and returns the signed difference in days.
+    ///
+    /// Unlike the absolute [`Date::day_difference`] this is negative if `rhs` is after `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Date;
+    /// let earlier: Date = Date::new_num(2024, 2, 28).unwrap();
+    /// let later: Date = Date::new_num(2024, 3, 1).unwrap();
+    ///
+    /// // Positive
+    /// assert_eq!(later - earlier, 2);
+    ///
+    /// // Negative
+    /// assert_eq!(earlier - later, -2);
+    /// assert_eq!(earlier.day_difference(&later), 2);
+    /// ```
+    fn sub(self, rhs: Date) -> Self::Output {
+        self.to_days() - rhs.to_days()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_round_trips() {
+        let mut date: Date = Date::new_num(Year::MIN, 1, 1).unwrap();
+        while let Ok(next_date) = date.add_days(1) {
+            date.assert_format_round_trips();
+            date = next_date;
+        }
+        date.assert_format_round_trips();
+        assert_eq!(date, Date::new_num(Year::MAX, 12, 31).unwrap());
     }
 }
@@ -41,11 +41,10 @@ impl Day {
     /// ```
     #[inline]
     pub const fn new(day: u8, month: Month, year: Year) -> Result<Self, ChronoError> {
-        let days_in_month: u8 = month.days_in_month(year);
-
-        if day >= 1_u8 && day <= days_in_month {
+        if month.is_valid_day(day, year) {
             Ok(Self { day })
         } else {
+            let days_in_month: u8 = month.days_in_month(year);
             Err(ChronoError::DayError { day, days_in_month })
         }
     }
@@ -75,9 +74,7 @@ impl Day {
     pub const fn new_const(day: u8, month: u8, year: i32) -> Self {
         let month: Month = Month::new_const(month);
         let year: Year = Year::new_const(year);
-        let days_in_month: u8 = month.days_in_month(year);
-
-        if day >= 1_u8 && day <= days_in_month {
+        if month.is_valid_day(day, year) {
             Self { day }
         } else {
             panic!("Invalid day")
@@ -110,6 +107,33 @@ impl Day {
     pub const fn value(&self) -> u8 {
         self.day
     }
+
+    /// Returns an iterator over all valid [`Day`]s of the `month` in the `year`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Day, Month, Year};
+    /// // Leap year
+    /// let days: Vec<Day> = Day::all_for(Month::February, Year::new(2024).unwrap()).collect();
+    /// assert_eq!(days.len(), 29);
+    /// assert_eq!(days.first().unwrap().value(), Day::MIN);
+    /// assert_eq!(days.last().unwrap().value(), 29);
+    ///
+    /// assert_eq!(Day::all_for(Month::December, Year::new(2025).unwrap()).count(), Day::MAX_POSSIBLE as usize);
+    /// ```
+    #[inline]
+    pub fn all_for(month: Month, year: Year) -> impl Iterator<Item = Day> {
+        (Self::MIN..=month.days_in_month(year)).map(Day::new_unchecked) // safe
+    }
+
+    /// The smallest day of any month.
+    pub const MIN: u8 = 1_u8;
+
+    /// The largest day of any month.
+    ///
+    /// Not every month has this many days, see [`Month::days_in_month`].
+    pub const MAX_POSSIBLE: u8 = 31_u8;
 }
 
 impl Display for Day {
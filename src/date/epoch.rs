@@ -0,0 +1,22 @@
+//! This module contains the implementation of the [`Epoch`] enum.
+
+#[allow(unused_imports)]
+use crate::Date;
+
+/// A representation of an [`Epoch`] from which days are counted.
+///
+/// See [`Date::days_since`] and [`Date::from_days_since`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Epoch {
+    /// Days since 01.01.1970, which is day `0`.
+    Unix1970,
+
+    /// The serial numbers of Excel, where 01.01.1900 is day `1`.
+    ///
+    /// Excel treats 1900 as a leap year, so day `60` is the non-existent 29.02.1900.
+    /// This bug is reproduced, so all serial numbers from 01.03.1900 onwards match Excel.
+    Excel1900,
+
+    /// The Rata Die count, where 01.01.0001 is day `1`.
+    RataDie,
+}
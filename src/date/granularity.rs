@@ -0,0 +1,19 @@
+//! This module contains the implementation of the [`Granularity`] enum.
+
+#[allow(unused_imports)]
+use crate::Date;
+
+/// A representation of the calendar periods a [`Date`] can be rounded to.
+///
+/// See [`Date::round_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    /// Periods starting on the first day of each month.
+    Month,
+
+    /// Periods starting on 01.01., 01.04., 01.07. and 01.10.
+    Quarter,
+
+    /// Periods starting on 01.01.
+    Year,
+}
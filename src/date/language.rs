@@ -0,0 +1,16 @@
+//! This module contains the implementation of the [`Language`] enum.
+
+#[allow(unused_imports)]
+use crate::Date;
+
+/// A representation of the languages a [`Date`] can be formatted in.
+///
+/// See [`Date::format_with_weekday`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    /// English names, e.g. "Saturday".
+    English,
+
+    /// German names, e.g. "Samstag".
+    German,
+}
@@ -1,13 +1,28 @@
 mod age;
 mod chrono_error;
+#[allow(clippy::module_inception)]
 mod date;
 mod day;
+mod epoch;
+mod granularity;
+mod language;
 mod month;
+mod period_relation;
+mod season;
+mod weekday;
+mod weekend_rule;
 mod year;
 
 pub use age::Age;
 pub use chrono_error::ChronoError;
 pub use date::Date;
 pub use day::Day;
+pub use epoch::Epoch;
+pub use granularity::Granularity;
+pub use language::Language;
 pub use month::Month;
+pub use period_relation::PeriodRelation;
+pub use season::Season;
+pub use weekday::Weekday;
+pub use weekend_rule::WeekendRule;
 pub use year::Year;
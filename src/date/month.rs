@@ -2,7 +2,9 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use crate::{ChronoError, Year};
+use crate::{ChronoError, Season, Year};
+#[allow(unused_imports)]
+use crate::{Date, Day};
 
 /// A representation of a [`Month`].
 ///
@@ -122,6 +124,7 @@ impl Month {
     /// Creates a new [`Month`] instance based on a string.
     ///
     /// This can be a string of a number or a string of the written month.
+    /// The written month may be an english or german name or abbreviation, e.g. "March", "Mar" or "März".
     ///
     /// # Errors
     ///
@@ -144,6 +147,12 @@ impl Month {
     /// let month: Month = Month::from_string("Aug").unwrap();
     /// assert_eq!(month, Month::August);
     ///
+    /// // String of german name
+    /// let month: Month = Month::from_string("Dezember").unwrap();
+    /// assert_eq!(month, Month::December);
+    /// assert_eq!(Month::from_string("März"), Ok(Month::March));
+    /// assert_eq!(Month::from_string("Mai"), Ok(Month::May));
+    ///
     /// // MonthError
     /// let month_error: ChronoError = Month::from_string("13").err().unwrap();
     /// assert_eq!(month_error, ChronoError::MonthError(13));
@@ -155,6 +164,10 @@ impl Month {
     /// // ParseError
     /// let parse_error: ChronoError = Month::from_string("1000").err().unwrap();
     /// assert_eq!(parse_error, ChronoError::ParseError);
+    ///
+    /// // TryFrom
+    /// assert_eq!(Month::try_from("June"), Ok(Month::June));
+    /// assert_eq!(Month::try_from("6"), Ok(Month::June));
     /// ```
     #[inline]
     pub fn from_string(string: &str) -> Result<Self, ChronoError> {
@@ -177,7 +190,7 @@ impl Month {
             "october" | "oct" => Ok(Month::October),
             "november" | "nov" => Ok(Month::November),
             "december" | "dec" => Ok(Month::December),
-            _ => Err(ChronoError::ParseError)?,
+            _ => Month::from_name_de(string),
         }
     }
 
@@ -233,6 +246,62 @@ impl Month {
         }
     }
 
+    /// Returns the previous month before the current one.
+    ///
+    /// # Notes
+    ///
+    /// This will wrap over to [`Month::December`] when calling [`Month::prev`] on [`Month::January`].
+    /// This method will not signal this jump to the caller.
+    /// See [`Month::add_months`] for this behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Month;
+    /// let month: Month = Month::June;
+    /// let prev_month: Month = month.prev();
+    /// assert_eq!(prev_month, Month::May);
+    ///
+    /// let month: Month = Month::January;
+    /// let prev_month: Month = month.prev();
+    /// assert_eq!(prev_month, Month::December);
+    /// ```
+    #[inline]
+    pub const fn prev(&self) -> Self {
+        match self {
+            Month::January => Month::December,
+            Month::February => Month::January,
+            Month::March => Month::February,
+            Month::April => Month::March,
+            Month::May => Month::April,
+            Month::June => Month::May,
+            Month::July => Month::June,
+            Month::August => Month::July,
+            Month::September => Month::August,
+            Month::October => Month::September,
+            Month::November => Month::October,
+            Month::December => Month::November,
+        }
+    }
+
+    /// Returns an iterator over all [`Month`]s in calendar order.
+    ///
+    /// See [`Month::ALL`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Month;
+    /// let months: Vec<Month> = Month::all().collect();
+    /// assert_eq!(months.len(), 12);
+    /// assert_eq!(months.first(), Some(&Month::January));
+    /// assert_eq!(months.last(), Some(&Month::December));
+    /// ```
+    #[inline]
+    pub fn all() -> impl Iterator<Item = Month> {
+        Self::ALL.into_iter()
+    }
+
     /// Adds a number of months to a [`Month`] instance and returns the new [`Month`] and the number of years passed.
     ///
     /// To subtract use a negative sign.
@@ -240,7 +309,7 @@ impl Month {
     /// # Errors
     ///
     /// * [`ChronoError::OverflowError`] - The `months` argument was too large.
-    /// Will only happen, when adding approximately [`i32::MAX`] months.
+    ///   Will only happen, when adding approximately [`i32::MAX`] months.
     ///
     /// # Examples
     ///
@@ -289,6 +358,72 @@ impl Month {
         Ok((new_month, year_offset))
     }
 
+    /// Adds a number of months to a [`Month`] instance and returns only the new [`Month`].
+    ///
+    /// To subtract use a negative sign.
+    ///
+    /// Unlike [`Month::add_months`] the number of years passed is ignored, so this can not fail.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Month;
+    /// assert_eq!(Month::December.wrapping_add(2), Month::February);
+    /// assert_eq!(Month::January.wrapping_add(-1), Month::December);
+    /// assert_eq!(Month::March.wrapping_add(i32::MAX), Month::October);
+    /// ```
+    #[inline]
+    pub const fn wrapping_add(&self, months: i32) -> Self {
+        let index: i32 = (*self as i32 - 1 + months.rem_euclid(12)) % 12;
+
+        Month::new_const(index as u8 + 1)
+    }
+
+    /// Returns the `n` [`Month`]s up to and including the [`Month`] itself in chronological order.
+    ///
+    /// The months wrap around the end of the year, so the years are ignored.
+    /// If `n` is larger than 12 months are repeated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Month;
+    /// assert_eq!(
+    ///     Month::March.last_n(4),
+    ///     vec![Month::December, Month::January, Month::February, Month::March]
+    /// );
+    /// assert!(Month::March.last_n(0).is_empty());
+    /// ```
+    #[inline]
+    pub fn last_n(&self, n: u8) -> Vec<Month> {
+        (0..n as i32)
+            .rev()
+            .map(|offset| self.wrapping_add(-offset))
+            .collect()
+    }
+
+    /// Returns the `n` [`Month`]s starting with and including the [`Month`] itself in chronological order.
+    ///
+    /// The months wrap around the end of the year, so the years are ignored.
+    /// If `n` is larger than 12 months are repeated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Month;
+    /// assert_eq!(
+    ///     Month::November.next_n(4),
+    ///     vec![Month::November, Month::December, Month::January, Month::February]
+    /// );
+    /// assert!(Month::November.next_n(0).is_empty());
+    /// ```
+    #[inline]
+    pub fn next_n(&self, n: u8) -> Vec<Month> {
+        (0..n as i32)
+            .map(|offset| self.wrapping_add(offset))
+            .collect()
+    }
+
     /// Returns the number of days in a [`Month`].
     ///
     /// This will also take leap years into account.
@@ -326,11 +461,169 @@ impl Month {
             | Month::December => 31_u8,
         }
     }
-}
 
-impl Display for Month {
-    fn fmt(&self, format: &mut Formatter<'_>) -> fmt::Result {
-        let name = match self {
+    /// Checks if a `day` exists in a [`Month`] of a given [`Year`].
+    ///
+    /// This is the check used by [`Day::new`] without creating a [`Day`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Month, Year};
+    /// // Leap year
+    /// assert!(Month::February.is_valid_day(29, Year::new(2024).unwrap()));
+    ///
+    /// // Not leap year
+    /// assert!(!Month::February.is_valid_day(29, Year::new(2023).unwrap()));
+    ///
+    /// assert!(!Month::January.is_valid_day(0, Year::new(2024).unwrap()));
+    /// ```
+    #[inline]
+    pub const fn is_valid_day(&self, day: u8, year: Year) -> bool {
+        day >= 1_u8 && day <= self.days_in_month(year)
+    }
+
+    /// Checks if a [`Month`] always has 31 days.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Month;
+    /// let long: Vec<u8> = (1..=12)
+    ///     .map(|number| Month::new(number).unwrap())
+    ///     .filter(Month::is_31_days)
+    ///     .map(|month| month.value())
+    ///     .collect();
+    /// assert_eq!(long, vec![1, 3, 5, 7, 8, 10, 12]);
+    /// ```
+    #[inline]
+    pub const fn is_31_days(&self) -> bool {
+        matches!(
+            self,
+            Month::January
+                | Month::March
+                | Month::May
+                | Month::July
+                | Month::August
+                | Month::October
+                | Month::December
+        )
+    }
+
+    /// Checks if a [`Month`] always has 30 days.
+    ///
+    /// [`Month::February`] is neither, use [`Month::days_in_month`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Month;
+    /// let short: Vec<u8> = (1..=12)
+    ///     .map(|number| Month::new(number).unwrap())
+    ///     .filter(Month::is_30_days)
+    ///     .map(|month| month.value())
+    ///     .collect();
+    /// assert_eq!(short, vec![4, 6, 9, 11]);
+    ///
+    /// assert!(!Month::February.is_30_days());
+    /// assert!(!Month::February.is_31_days());
+    /// ```
+    #[inline]
+    pub const fn is_30_days(&self) -> bool {
+        matches!(
+            self,
+            Month::April | Month::June | Month::September | Month::November
+        )
+    }
+
+    /// Returns the absolute number of months between two [`Month`]s within a year.
+    ///
+    /// This does not wrap over the end of the year,
+    /// so [`Month::December`] and [`Month::January`] are 11 months apart.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Month;
+    /// assert_eq!(Month::March.distance(&Month::June), 3);
+    /// assert_eq!(Month::June.distance(&Month::March), 3);
+    /// assert_eq!(Month::December.distance(&Month::January), 11);
+    /// ```
+    #[inline]
+    pub const fn distance(&self, other: &Month) -> u8 {
+        self.value().abs_diff(other.value())
+    }
+
+    /// Returns the number of months forward from one [`Month`] to another, between 0 and 11.
+    ///
+    /// Unlike [`Month::distance`] this wraps over the end of the year,
+    /// so [`Month::December`] to [`Month::January`] is 1 month.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Month;
+    /// assert_eq!(Month::November.distance_forward(&Month::February), 3);
+    /// assert_eq!(Month::February.distance_forward(&Month::November), 9);
+    /// assert_eq!(Month::June.distance_forward(&Month::June), 0);
+    /// ```
+    #[inline]
+    pub const fn distance_forward(&self, other: &Month) -> u8 {
+        (other.value() + 12_u8 - self.value()) % 12_u8
+    }
+
+    /// Returns the calendar quarter of the [`Month`], between 1 and 4.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Month;
+    /// assert_eq!(Month::February.quarter(), 1);
+    /// assert_eq!(Month::April.quarter(), 2);
+    /// assert_eq!(Month::September.quarter(), 3);
+    /// assert_eq!(Month::December.quarter(), 4);
+    /// ```
+    #[inline]
+    pub const fn quarter(&self) -> u8 {
+        (self.value() - 1_u8) / 3_u8 + 1_u8
+    }
+
+    /// Returns the meteorological [`Season`] of the [`Month`].
+    ///
+    /// Each season covers three full months, starting with [`Month::December`] for [`Season::Winter`].
+    /// See [`Date::astronomical_season`] for the astronomical seasons.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Month, Season};
+    /// assert_eq!(Month::January.season(), Season::Winter);
+    /// assert_eq!(Month::April.season(), Season::Spring);
+    /// assert_eq!(Month::August.season(), Season::Summer);
+    /// assert_eq!(Month::November.season(), Season::Autumn);
+    /// assert_eq!(Month::December.season(), Season::Winter);
+    /// ```
+    #[inline]
+    pub const fn season(&self) -> Season {
+        match self {
+            Month::December | Month::January | Month::February => Season::Winter,
+            Month::March | Month::April | Month::May => Season::Spring,
+            Month::June | Month::July | Month::August => Season::Summer,
+            Month::September | Month::October | Month::November => Season::Autumn,
+        }
+    }
+
+    /// Returns the english name of the [`Month`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Month;
+    /// assert_eq!(Month::March.name(), "March");
+    /// ```
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match self {
             Month::January => "January",
             Month::February => "February",
             Month::March => "March",
@@ -343,8 +636,109 @@ impl Display for Month {
             Month::October => "October",
             Month::November => "November",
             Month::December => "December",
-        };
-        write!(format, "{}", name)
+        }
+    }
+
+    /// Returns the english three-letter abbreviation of the [`Month`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Month;
+    /// assert_eq!(Month::August.abbreviation(), "Aug");
+    /// assert_eq!(Month::from_string(Month::May.abbreviation()), Ok(Month::May));
+    /// ```
+    #[inline]
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            Month::January => "Jan",
+            Month::February => "Feb",
+            Month::March => "Mar",
+            Month::April => "Apr",
+            Month::May => "May",
+            Month::June => "Jun",
+            Month::July => "Jul",
+            Month::August => "Aug",
+            Month::September => "Sep",
+            Month::October => "Oct",
+            Month::November => "Nov",
+            Month::December => "Dec",
+        }
+    }
+
+    /// Returns the german name of the [`Month`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Month;
+    /// assert_eq!(Month::March.name_de(), "März");
+    /// ```
+    #[inline]
+    pub const fn name_de(&self) -> &'static str {
+        match self {
+            Month::January => "Januar",
+            Month::February => "Februar",
+            Month::March => "März",
+            Month::April => "April",
+            Month::May => "Mai",
+            Month::June => "Juni",
+            Month::July => "Juli",
+            Month::August => "August",
+            Month::September => "September",
+            Month::October => "Oktober",
+            Month::November => "November",
+            Month::December => "Dezember",
+        }
+    }
+
+    /// Creates a new [`Month`] instance based on a german name.
+    ///
+    /// This accepts the full name of [`Month::name_de`] and common abbreviations,
+    /// both case insensitive and with an optional trailing '.'.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::ParseError`] - Could not match `string` to a german month name.
+    #[inline]
+    pub(crate) fn from_name_de(string: &str) -> Result<Self, ChronoError> {
+        match string.trim_end_matches('.').to_lowercase().as_str() {
+            "januar" | "jan" => Ok(Month::January),
+            "februar" | "feb" => Ok(Month::February),
+            "märz" | "maerz" | "mär" | "mrz" => Ok(Month::March),
+            "april" | "apr" => Ok(Month::April),
+            "mai" => Ok(Month::May),
+            "juni" | "jun" => Ok(Month::June),
+            "juli" | "jul" => Ok(Month::July),
+            "august" | "aug" => Ok(Month::August),
+            "september" | "sep" | "sept" => Ok(Month::September),
+            "oktober" | "okt" => Ok(Month::October),
+            "november" | "nov" => Ok(Month::November),
+            "dezember" | "dez" => Ok(Month::December),
+            _ => Err(ChronoError::ParseError),
+        }
+    }
+
+    /// All [`Month`]s in calendar order.
+    pub const ALL: [Month; 12] = [
+        Month::January,
+        Month::February,
+        Month::March,
+        Month::April,
+        Month::May,
+        Month::June,
+        Month::July,
+        Month::August,
+        Month::September,
+        Month::October,
+        Month::November,
+        Month::December,
+    ];
+}
+
+impl Display for Month {
+    fn fmt(&self, format: &mut Formatter<'_>) -> fmt::Result {
+        write!(format, "{}", self.name())
     }
 }
 
@@ -375,6 +769,14 @@ impl TryFrom<i32> for Month {
     }
 }
 
+impl TryFrom<&str> for Month {
+    type Error = ChronoError;
+
+    fn try_from(month: &str) -> Result<Self, Self::Error> {
+        Month::from_string(month)
+    }
+}
+
 impl From<Month> for usize {
     fn from(month: Month) -> usize {
         month.value() as usize
@@ -0,0 +1,29 @@
+//! This module contains the implementation of the [`PeriodRelation`] enum.
+
+#[allow(unused_imports)]
+use crate::Date;
+
+/// A representation of how two periods of [`Date`]s relate to each other.
+///
+/// Both the start and the end [`Date`] belong to a period.
+/// See [`Date::relate_periods`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeriodRelation {
+    /// The periods have no day in common and there is at least one day between them.
+    Disjoint,
+
+    /// The periods have no day in common, but one ends the day before the other starts.
+    Touching,
+
+    /// The periods have some days in common, but neither contains the other.
+    Overlapping,
+
+    /// The first period contains the second one.
+    Contains,
+
+    /// The first period is contained by the second one.
+    ContainedBy,
+
+    /// Both periods have the same start and end.
+    Equal,
+}
@@ -0,0 +1,22 @@
+//! This module contains the implementation of the [`Season`] enum.
+
+#[allow(unused_imports)]
+use crate::{Date, Month};
+
+/// A representation of the four seasons of the northern hemisphere.
+///
+/// See [`Month::season`] and [`Date::astronomical_season`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Season {
+    /// The winter.
+    Winter,
+
+    /// The spring.
+    Spring,
+
+    /// The summer.
+    Summer,
+
+    /// The autumn.
+    Autumn,
+}
@@ -0,0 +1,194 @@
+//! This module contains the implementation of the [`Weekday`] enum.
+
+use std::fmt::{self, Display, Formatter};
+
+#[allow(unused_imports)]
+use crate::Date;
+
+/// A representation of a [`Weekday`].
+///
+/// The values follow ISO 8601, starting with [`Weekday::Monday`] as `1`.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Weekday {
+    /// Monday (Mon)
+    Monday = 1,
+
+    /// Tuesday (Tue)
+    Tuesday = 2,
+
+    /// Wednesday (Wed)
+    Wednesday = 3,
+
+    /// Thursday (Thu)
+    Thursday = 4,
+
+    /// Friday (Fri)
+    Friday = 5,
+
+    /// Saturday (Sat)
+    Saturday = 6,
+
+    /// Sunday (Sun)
+    Sunday = 7,
+}
+
+impl Weekday {
+    /// Returns the value of the [`Weekday`] instance.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Weekday;
+    /// assert_eq!(Weekday::Monday.value(), 1);
+    /// assert_eq!(Weekday::Sunday.value(), 7);
+    /// ```
+    #[inline]
+    pub const fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the english name of the [`Weekday`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Weekday;
+    /// assert_eq!(Weekday::Wednesday.name(), "Wednesday");
+    /// ```
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+
+    /// Returns the german name of the [`Weekday`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Weekday;
+    /// assert_eq!(Weekday::Wednesday.name_de(), "Mittwoch");
+    /// ```
+    #[inline]
+    pub const fn name_de(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "Montag",
+            Weekday::Tuesday => "Dienstag",
+            Weekday::Wednesday => "Mittwoch",
+            Weekday::Thursday => "Donnerstag",
+            Weekday::Friday => "Freitag",
+            Weekday::Saturday => "Samstag",
+            Weekday::Sunday => "Sonntag",
+        }
+    }
+
+    /// Returns the english three-letter abbreviation of the [`Weekday`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Weekday;
+    /// let weekdays: [Weekday; 7] = [
+    ///     Weekday::Monday,
+    ///     Weekday::Tuesday,
+    ///     Weekday::Wednesday,
+    ///     Weekday::Thursday,
+    ///     Weekday::Friday,
+    ///     Weekday::Saturday,
+    ///     Weekday::Sunday,
+    /// ];
+    /// let abbreviations: Vec<&str> = weekdays.iter().map(Weekday::abbr).collect();
+    /// assert_eq!(abbreviations, vec!["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]);
+    /// ```
+    #[inline]
+    pub const fn abbr(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "Mon",
+            Weekday::Tuesday => "Tue",
+            Weekday::Wednesday => "Wed",
+            Weekday::Thursday => "Thu",
+            Weekday::Friday => "Fri",
+            Weekday::Saturday => "Sat",
+            Weekday::Sunday => "Sun",
+        }
+    }
+
+    /// Returns the german two-letter abbreviation of the [`Weekday`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Weekday;
+    /// let weekdays: [Weekday; 7] = [
+    ///     Weekday::Monday,
+    ///     Weekday::Tuesday,
+    ///     Weekday::Wednesday,
+    ///     Weekday::Thursday,
+    ///     Weekday::Friday,
+    ///     Weekday::Saturday,
+    ///     Weekday::Sunday,
+    /// ];
+    /// let abbreviations: Vec<&str> = weekdays.iter().map(Weekday::abbr_de).collect();
+    /// assert_eq!(abbreviations, vec!["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"]);
+    ///
+    /// let names: Vec<&str> = weekdays.iter().map(Weekday::name_de).collect();
+    /// assert_eq!(
+    ///     names,
+    ///     vec!["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag"]
+    /// );
+    /// ```
+    #[inline]
+    pub const fn abbr_de(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "Mo",
+            Weekday::Tuesday => "Di",
+            Weekday::Wednesday => "Mi",
+            Weekday::Thursday => "Do",
+            Weekday::Friday => "Fr",
+            Weekday::Saturday => "Sa",
+            Weekday::Sunday => "So",
+        }
+    }
+
+    /// Returns the [`Weekday`] `offset` days after a [`Weekday::Monday`].
+    ///
+    /// The `offset` is wrapped, so any [`i32`] is valid.
+    #[inline]
+    pub(crate) const fn from_offset(offset: i32) -> Self {
+        match offset.rem_euclid(7) {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+}
+
+impl Display for Weekday {
+    fn fmt(&self, format: &mut Formatter<'_>) -> fmt::Result {
+        write!(format, "{}", self.name())
+    }
+}
+
+impl From<Weekday> for u8 {
+    fn from(weekday: Weekday) -> Self {
+        weekday.value()
+    }
+}
+
+impl From<Weekday> for i32 {
+    fn from(weekday: Weekday) -> Self {
+        weekday.value() as i32
+    }
+}
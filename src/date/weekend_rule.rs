@@ -0,0 +1,20 @@
+//! This module contains the implementation of the [`WeekendRule`] enum.
+
+#[allow(unused_imports)]
+use crate::{Date, Weekday};
+
+/// A representation of how a [`Date`] on a weekend is moved to a business day.
+///
+/// See [`Date::to_nearest_business_day`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeekendRule {
+    /// Moves to the following [`Weekday::Monday`].
+    Following,
+
+    /// Moves to the preceding [`Weekday::Friday`].
+    Preceding,
+
+    /// Moves a [`Weekday::Saturday`] to the preceding [`Weekday::Friday`]
+    /// and a [`Weekday::Sunday`] to the following [`Weekday::Monday`].
+    Nearest,
+}
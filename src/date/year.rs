@@ -2,7 +2,7 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use crate::ChronoError;
+use crate::{ChronoError, PensionAge};
 
 /// A representation of a [`Year`].
 ///
@@ -69,6 +69,49 @@ impl Year {
         }
     }
 
+    /// Creates a new [`Year`] instance or returns [`None`] if the `year` is invalid.
+    ///
+    /// This is the [`Option`] counterpart of [`Year::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Year;
+    /// assert_eq!(Year::checked(2025), Some(Year::new(2025).unwrap()));
+    /// assert_eq!(Year::checked(Year::MIN - 1), None);
+    /// assert_eq!(Year::checked(Year::MAX + 1), None);
+    /// ```
+    #[inline]
+    pub const fn checked(year: i32) -> Option<Self> {
+        match Self::new(year) {
+            Ok(year) => Some(year),
+            Err(_) => None,
+        }
+    }
+
+    /// Creates a new [`Year`] instance clamping the `year` between [`Year::MIN`] and [`Year::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Year;
+    /// assert_eq!(Year::saturating_from(2025).value(), 2025);
+    /// assert_eq!(Year::saturating_from(1066).value(), Year::MIN);
+    /// assert_eq!(Year::saturating_from(i32::MAX).value(), Year::MAX);
+    /// ```
+    #[inline]
+    pub const fn saturating_from(year: i32) -> Self {
+        let year: i32 = if year < Self::MIN {
+            Self::MIN
+        } else if year > Self::MAX {
+            Self::MAX
+        } else {
+            year
+        };
+
+        Self { year }
+    }
+
     /// Returns a new [`Year`] instance without any checks.
     ///
     /// # Safety
@@ -180,6 +223,43 @@ impl Year {
         }
     }
 
+    /// Checks if any year between `start` and `end`, both included, is a leap year.
+    ///
+    /// The years may be given in any order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Year;
+    /// // Single year
+    /// assert!(!Year::range_has_leap(Year::new(2025).unwrap(), Year::new(2025).unwrap()));
+    /// assert!(Year::range_has_leap(Year::new(2024).unwrap(), Year::new(2024).unwrap()));
+    ///
+    /// // Two years
+    /// assert!(Year::range_has_leap(Year::new(2023).unwrap(), Year::new(2024).unwrap()));
+    /// assert!(Year::range_has_leap(Year::new(2024).unwrap(), Year::new(2023).unwrap()));
+    ///
+    /// // 1900 is not a leap year
+    /// assert!(!Year::range_has_leap(Year::new(1900).unwrap(), Year::new(1903).unwrap()));
+    /// ```
+    #[inline]
+    pub const fn range_has_leap(start: Year, end: Year) -> bool {
+        let (mut year, last) = if start.year <= end.year {
+            (start.year, end.year)
+        } else {
+            (end.year, start.year)
+        };
+
+        while year <= last {
+            if Year::new_unchecked(year).is_leap_year() {
+                return true;
+            }
+            year += 1;
+        }
+
+        false
+    }
+
     /// Adds a number of years to a [`Year`] instance.
     ///
     /// To subtract use a negative sign.
@@ -187,7 +267,7 @@ impl Year {
     /// # Errors
     ///
     /// * [`ChronoError::YearError`] - Something in [`Year::new`] went wrong.
-    /// This is caused, if the resulting [`Year`] is not between [`Year::MIN`] and [`Year::MAX`].
+    ///   This is caused, if the resulting [`Year`] is not between [`Year::MIN`] and [`Year::MAX`].
     /// * [`ChronoError::OverflowError`] - The `years` argument was too large.
     ///
     /// # Examples
@@ -224,6 +304,69 @@ impl Year {
         Self::new(new_year)
     }
 
+    /// Returns the [`PensionAge`] of a person born in this [`Year`].
+    ///
+    /// See [`PensionAge::from_birthyear`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, PensionAge};
+    /// let birth_date: Date = Date::new_num(2000, 5, 17).unwrap();
+    /// let pension_age: PensionAge = birth_date.year().pension_age();
+    /// assert_eq!(pension_age.pension_years().value(), 67);
+    /// assert_eq!(pension_age.pension_months().value(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn pension_age(&self) -> PensionAge {
+        PensionAge::from_birthyear(*self)
+    }
+
+    /// Returns the phase of the transition based on SGB VI § 235 this [`Year`] belongs to as birthyear.
+    ///
+    /// * `"pre-transition"` - Birthyears up to 1957 with a pension age of 65 years plus 0 to 11 months.
+    /// * `"transition"` - Birthyears from 1958 up to 1963 with a pension age of 66 years plus 0 to 10 months.
+    /// * `"post-transition"` - Birthyears from 1964 with a pension age of 67 years.
+    ///
+    /// The phases follow the whole years of the pension age.
+    /// So the birthyears from 1947 up to 1957, whose pension age already rises by months, are `"pre-transition"`.
+    ///
+    /// See [`PensionAge::from_birthyear`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Year;
+    /// assert_eq!(Year::new(1950).unwrap().pension_cohort_label(), "pre-transition");
+    /// assert_eq!(Year::new(1960).unwrap().pension_cohort_label(), "transition");
+    /// assert_eq!(Year::new(2000).unwrap().pension_cohort_label(), "post-transition");
+    /// ```
+    #[inline]
+    pub const fn pension_cohort_label(&self) -> &'static str {
+        match self.year {
+            ..=1957 => "pre-transition",
+            1958..=1963 => "transition",
+            1964.. => "post-transition",
+        }
+    }
+
+    /// Returns the absolute number of years between two [`Year`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::Year;
+    /// let year_1: Year = Year::new(2000).unwrap();
+    /// let year_2: Year = Year::new(2025).unwrap();
+    /// assert_eq!(year_1.distance(&year_2), 25);
+    /// assert_eq!(year_2.distance(&year_1), 25);
+    /// ```
+    #[inline]
+    pub const fn distance(&self, other: &Year) -> u32 {
+        self.year.abs_diff(other.year)
+    }
+
     /// The smallest reasonable year supported.
     ///
     /// This is just set to easily find mistakes when handling dates of birth.
@@ -4,7 +4,9 @@ mod rounding;
 
 pub use date::ChronoError;
 pub use date::{Age, Date};
-pub use date::{Day, Month, Year};
+pub use date::{
+    Day, Epoch, Granularity, Language, Month, PeriodRelation, Season, Weekday, WeekendRule, Year,
+};
 
 pub use rata_temporis::Accuracy;
 pub use rata_temporis::{PensionAge, PensionAgeError, PensionMonths, PensionYears};
@@ -1,5 +1,6 @@
 mod accuracy;
 mod pension_time;
+#[allow(clippy::module_inception)]
 mod rata_temporis;
 
 pub use accuracy::Accuracy;
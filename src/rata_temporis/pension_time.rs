@@ -5,7 +5,7 @@ use std::{
     fmt::{self, Display, Formatter},
 };
 
-use crate::Year;
+use crate::{Age, ChronoError, Date, Year};
 
 /// Handles the amount of months between `birth_date` and `pension_date`.
 ///
@@ -99,6 +99,28 @@ impl PensionMonths {
     pub const fn value(&self) -> u8 {
         self.pension_months
     }
+
+    /// Returns the [`PensionMonths`] as a German string.
+    ///
+    /// This is the German counterpart to the [`Display`] implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::PensionMonths;
+    /// // Singular
+    /// let pension_months: PensionMonths = PensionMonths::new(1).unwrap();
+    /// assert_eq!(pension_months.to_string_de(), "1 Monat");
+    ///
+    /// // Plural
+    /// let pension_months: PensionMonths = PensionMonths::new(4).unwrap();
+    /// assert_eq!(pension_months.to_string_de(), "4 Monate");
+    /// ```
+    #[must_use]
+    pub fn to_string_de(&self) -> String {
+        let months = self.value();
+        format!("{} Monat{}", months, if months == 1 { "" } else { "e" })
+    }
 }
 
 impl Default for PensionMonths {
@@ -235,6 +257,23 @@ impl PensionYears {
     pub const fn value(&self) -> u8 {
         self.pension_years
     }
+
+    /// Returns the [`PensionYears`] as a German string.
+    ///
+    /// This is the German counterpart to the [`Display`] implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::PensionYears;
+    /// let pension_years: PensionYears = PensionYears::new(67).unwrap();
+    /// assert_eq!(pension_years.to_string_de(), "67 Jahre");
+    /// ```
+    #[must_use]
+    pub fn to_string_de(&self) -> String {
+        let years = self.value();
+        format!("{} Jahr{}", years, if years == 1 { "" } else { "e" })
+    }
 }
 
 impl Default for PensionYears {
@@ -442,6 +481,139 @@ impl PensionAge {
         }
     }
 
+    /// Returns the table of [`PensionAge`]s based on SGB VI § 235 for each birthyear of the transition.
+    ///
+    /// This covers the birthyears from 1946 (the last one before the transition) up to 1964
+    /// (the first one after the transition) using [`PensionAge::from_birthyear`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{PensionAge, Year};
+    /// let table: Vec<(Year, PensionAge)> = PensionAge::cohort_table();
+    /// assert_eq!(table.len(), 19);
+    ///
+    /// let (year, pension_age): (Year, PensionAge) = table[13];
+    /// assert_eq!(year.value(), 1959);
+    /// assert_eq!(pension_age, PensionAge::new_num(66, 2).unwrap());
+    ///
+    /// let (year, pension_age): (Year, PensionAge) = table[18];
+    /// assert_eq!(year.value(), 1964);
+    /// assert_eq!(pension_age, PensionAge::new_num(67, 0).unwrap());
+    /// ```
+    #[must_use]
+    pub fn cohort_table() -> Vec<(Year, PensionAge)> {
+        (1946..=1964)
+            .map(|birthyear: i32| {
+                let birthyear: Year = Year::new_unchecked(birthyear); // safe
+                (birthyear, Self::from_birthyear(birthyear))
+            })
+            .collect()
+    }
+
+    /// Returns a continuous approximation of the pension age in months for a `birthdate`.
+    ///
+    /// The [`PensionAge::total_months`] of the birthyear and the following birthyear are interpolated
+    /// linearly using the month of birth, e.g. a birth in July lies half-way in between.
+    ///
+    /// # Notes
+    ///
+    /// This is not a statutory pension age, which only depends on the birthyear according to SGB VI § 235.
+    /// It is meant as a modeling helper, e.g. for a smooth visualization of the transition.
+    /// Use [`PensionAge::from_birthyear`] for any legal calculation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, PensionAge, Year};
+    /// let statutory_1959: u32 = PensionAge::from_birthyear(Year::new(1959).unwrap()).total_months();
+    /// let statutory_1960: u32 = PensionAge::from_birthyear(Year::new(1960).unwrap()).total_months();
+    ///
+    /// // Mid of the birthyear
+    /// let birthdate: Date = Date::new_num(1959, 7, 1).unwrap();
+    /// let months: u32 = PensionAge::transition_months_for(&birthdate);
+    /// assert!(statutory_1959 < months && months < statutory_1960);
+    ///
+    /// // Start of the birthyear
+    /// let birthdate: Date = Date::new_num(1959, 1, 15).unwrap();
+    /// assert_eq!(PensionAge::transition_months_for(&birthdate), statutory_1959);
+    /// ```
+    #[must_use]
+    pub fn transition_months_for(birthdate: &Date) -> u32 {
+        let birthyear: Year = birthdate.year();
+        let next_birthyear: Year = Year::saturating_from(birthyear.value() + 1_i32);
+
+        let start: u32 = Self::from_birthyear(birthyear).total_months();
+        let end: u32 = Self::from_birthyear(next_birthyear).total_months();
+        let elapsed_months: u32 = u32::from(birthdate.month().value()) - 1_u32;
+
+        start + (end - start) * elapsed_months / 12_u32
+    }
+
+    /// The amount of insurance years needed to be a "besonders langjährig Versicherter".
+    pub const LONG_TERM_INSURANCE_YEARS: u8 = 45;
+
+    /// Returns the [`PensionAge`] of a "besonders langjährig Versicherter" based on SGB VI § 38 and § 236b.
+    ///
+    /// With at least [`PensionAge::LONG_TERM_INSURANCE_YEARS`] insurance years, the pension age is 63 years
+    /// for birthyears before 1953 and rises by 2 months per birthyear up to 65 years for birthyears from 1964.
+    /// With fewer insurance years, the regular [`PensionAge::from_birthyear`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{PensionAge, Year};
+    /// // Before transition
+    /// let birth_year: Year = Year::new(1950).unwrap();
+    /// let pension_age: PensionAge = PensionAge::for_long_term_insured(birth_year, 45);
+    /// assert_eq!(pension_age.pension_years().value(), 63);
+    /// assert_eq!(pension_age.pension_months().value(), 0);
+    ///
+    /// // During transition
+    /// let birth_year: Year = Year::new(1959).unwrap();
+    /// let pension_age: PensionAge = PensionAge::for_long_term_insured(birth_year, 45);
+    /// assert_eq!(pension_age.pension_years().value(), 64);
+    /// assert_eq!(pension_age.pension_months().value(), 2);
+    ///
+    /// // After transition
+    /// let birth_year: Year = Year::new(2000).unwrap();
+    /// let pension_age: PensionAge = PensionAge::for_long_term_insured(birth_year, 46);
+    /// assert_eq!(pension_age.pension_years().value(), 65);
+    /// assert_eq!(pension_age.pension_months().value(), 0);
+    ///
+    /// // Not enough insurance years
+    /// let birth_year: Year = Year::new(2000).unwrap();
+    /// let pension_age: PensionAge = PensionAge::for_long_term_insured(birth_year, 44);
+    /// assert_eq!(pension_age, PensionAge::from_birthyear(birth_year));
+    /// ```
+    #[must_use]
+    pub const fn for_long_term_insured(birthyear: Year, insurance_years: u8) -> PensionAge {
+        if insurance_years < Self::LONG_TERM_INSURANCE_YEARS {
+            return Self::from_birthyear(birthyear);
+        }
+
+        let (pension_years, pension_months): (u8, u8) = match birthyear.value() {
+            ..=1952 => (63, 0),
+            1953 => (63, 2),
+            1954 => (63, 4),
+            1955 => (63, 6),
+            1956 => (63, 8),
+            1957 => (63, 10),
+            1958 => (64, 0),
+            1959 => (64, 2),
+            1960 => (64, 4),
+            1961 => (64, 6),
+            1962 => (64, 8),
+            1963 => (64, 10),
+            1964.. => (65, 0),
+        };
+
+        PensionAge {
+            pension_years: PensionYears { pension_years },
+            pension_months: PensionMonths { pension_months },
+        }
+    }
+
     /// Returns the [`PensionYears`].
     #[must_use]
     #[inline]
@@ -475,6 +647,72 @@ impl PensionAge {
         (self.pension_years.value() as u32 * 12) + self.pension_months.value() as u32
     }
 
+    /// Returns the [`PensionAge`] deferred by the given amount of `months`.
+    ///
+    /// Months exceeding a full year are carried into the [`PensionYears`].
+    ///
+    /// # Errors
+    ///
+    /// * [`PensionAgeError::YearError`] - The deferred [`PensionAge`] exceeds [`PensionYears::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{PensionAge, PensionAgeError};
+    /// // Valid
+    /// let pension_age: PensionAge = PensionAge::new_num(67, 0).unwrap();
+    /// assert_eq!(pension_age.deferred_by(6).unwrap(), PensionAge::new_num(67, 6).unwrap());
+    ///
+    /// let pension_age: PensionAge = PensionAge::new_num(66, 10).unwrap();
+    /// assert_eq!(pension_age.deferred_by(4).unwrap(), PensionAge::new_num(67, 2).unwrap());
+    ///
+    /// // YearError
+    /// let pension_age: PensionAge = PensionAge::new_num(75, 0).unwrap();
+    /// let year_error: PensionAgeError = pension_age.deferred_by(12).err().unwrap();
+    /// assert_eq!(year_error, PensionAgeError::YearError { pension_years: 76 });
+    /// ```
+    #[inline]
+    pub const fn deferred_by(&self, months: u8) -> Result<PensionAge, PensionAgeError> {
+        let total_months: u32 = self.total_months() + months as u32;
+        // Both fit into u8, as 75 + 255 / 12 < 255
+        let pension_years: u8 = (total_months / 12) as u8;
+        let pension_months: u8 = (total_months % 12) as u8;
+
+        let pension_years: PensionYears = match PensionYears::new(pension_years) {
+            Ok(pension_years) => pension_years,
+            Err(error) => return Err(error),
+        };
+
+        // safe
+        let pension_months: PensionMonths = PensionMonths { pension_months };
+
+        Ok(Self {
+            pension_years,
+            pension_months,
+        })
+    }
+
+    /// Returns the [`PensionYears`] as an [`Age`].
+    ///
+    /// The [`PensionMonths`] are ignored.
+    ///
+    /// # Errors
+    ///
+    /// * [`ChronoError::AgeError`] - Something in [`Age::new`] went wrong.
+    ///   As [`PensionYears::MAX`] is smaller than [`Age::MAX`], this should never happen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Age, PensionAge};
+    /// let pension_age: PensionAge = PensionAge::new_num(67, 2).unwrap();
+    /// assert_eq!(pension_age.as_age().unwrap(), Age::new(67).unwrap());
+    /// ```
+    #[inline]
+    pub const fn as_age(&self) -> Result<Age, ChronoError> {
+        Age::new(self.pension_years.value())
+    }
+
     /// Creates a new [`PensionAge`] instance at exactly * years and 0 months.
     ///
     ///
@@ -522,6 +760,26 @@ pub enum PensionAgeError {
     YearError { pension_years: u8 },
 }
 
+impl PensionAgeError {
+    /// Checks if the [`PensionAgeError`] is caused by bad input, which can be fixed by the caller.
+    ///
+    /// This is the case for all variants.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::PensionAgeError;
+    /// assert!(PensionAgeError::MonthError { pension_months: 12 }.is_input_error());
+    /// assert!(PensionAgeError::YearError { pension_years: 90 }.is_input_error());
+    /// ```
+    #[inline]
+    pub const fn is_input_error(&self) -> bool {
+        match self {
+            PensionAgeError::MonthError { .. } | PensionAgeError::YearError { .. } => true,
+        }
+    }
+}
+
 impl Display for PensionAgeError {
     fn fmt(&self, format: &mut Formatter) -> Result<(), fmt::Error> {
         match self {
@@ -13,6 +13,23 @@ use crate::{ChronoError, PensionMonths, PensionYears};
 ///
 /// This is based on the §2 of the german ["Gesetz zur Verbesserung der betrieblichen Altersversorgung"](https://www.gesetze-im-internet.de/betravg/__2.html).
 /// It is defined as the actual service time divided by the possible service time up to the pension age.
+///
+/// The derived [`PartialEq`] compares the [`Date`]s, not the ratio.
+/// Use [`RataTemporis::ratio_key`] to compare by ratio.
+///
+/// # Examples
+///
+/// ```rust
+/// # use date::{Date, RataTemporis};
+/// let birth_date: Date = Date::new_num(2000, 1, 1).unwrap();
+/// let entry_date: Date = Date::new_num(2020, 1, 1).unwrap();
+/// let exit_date: Date = Date::new_num(2025, 12, 31).unwrap();
+/// let rata_temporis: RataTemporis = RataTemporis::new(birth_date, entry_date, exit_date).unwrap();
+/// assert_eq!(
+///     rata_temporis.to_string(),
+///     String::from("RataTemporis(birth=01.01.2000, entry=01.01.2020, exit=31.12.2025)")
+/// );
+/// ```
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct RataTemporis {
     /// The date of birth.
@@ -79,7 +96,7 @@ impl RataTemporis {
     /// # Errors
     ///
     /// * [`RataTemporisError::NegativeDifference`] - The difference between `entry_date` and `exit_date` is negative.
-    /// This should be prevented by the [`RataTemporis::new`] method.
+    ///   This should be prevented by the [`RataTemporis::new`] method.
     ///
     /// # Examples
     ///
@@ -126,11 +143,9 @@ impl RataTemporis {
         rounding: Rounding,
     ) -> Result<u32, RataTemporisError> {
         // Can not be negative
-        let m: i32 = match accuracy {
-            Accuracy::DayExact => self.entry_date.day_difference(&self.exit_date),
-            Accuracy::MonthExact => self.entry_date.month_difference(&self.exit_date, rounding),
-            Accuracy::YearExact => self.entry_date.year_difference(&self.exit_date, rounding),
-        };
+        let m: i32 = self
+            .entry_date
+            .difference_in(&self.exit_date, accuracy, rounding);
 
         let m: u32 = m
             .try_into()
@@ -196,24 +211,14 @@ impl RataTemporis {
         accuracy: Accuracy,
         rounding: Rounding,
     ) -> Result<u32, RataTemporisError> {
-        let pension_years: i32 = i32::from(pension_age.pension_years());
-        let pension_months: i32 = i32::from(pension_age.pension_months());
-
-        let pension_date: Date = self
-            .birth_date
-            .add_years(pension_years)
-            .map_err(|_| RataTemporisError::YearError { pension_years })?
-            .add_months(pension_months)
-            .map_err(|_| RataTemporisError::MonthError { pension_months })?;
+        let pension_date: Date = self.pension_date(pension_age)?;
 
         RataTemporisError::check_order(&self.entry_date, &pension_date)?;
 
         // Can not be negative
-        let n: i32 = match accuracy {
-            Accuracy::DayExact => self.entry_date.day_difference(&pension_date),
-            Accuracy::MonthExact => self.entry_date.month_difference(&pension_date, rounding),
-            Accuracy::YearExact => self.entry_date.year_difference(&pension_date, rounding),
-        };
+        let n: i32 = self
+            .entry_date
+            .difference_in(&pension_date, accuracy, rounding);
 
         let n: u32 = n
             .try_into()
@@ -222,6 +227,132 @@ impl RataTemporis {
         Ok(n)
     }
 
+    /// Returns the possible service (n) in calendar days.
+    ///
+    /// This counts the days from the `entry_date` up to the `pension_date`,
+    /// where the `entry_date` is counted and the `pension_date` is not.
+    /// So an `entry_date` equal to the `pension_date` yields `0` days.
+    /// This is the same as [`RataTemporis::possible_service`] with [`Accuracy::DayExact`].
+    ///
+    /// # Errors
+    ///
+    /// See [`RataTemporis::possible_service`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, RataTemporis, PensionAge};
+    /// // Pension date is 01.01.2065
+    /// // 45 years of 365 days and 12 leap days (2020, 2024, ..., 2064)
+    /// let birth_date: Date = Date::new_num(2000, 1, 1).unwrap();
+    /// let entry_date: Date = Date::new_num(2020, 1, 1).unwrap();
+    /// let exit_date: Date = Date::new_num(2025, 12, 31).unwrap();
+    /// let rata_temporis: RataTemporis = RataTemporis::new(birth_date, entry_date, exit_date).unwrap();
+    /// let n: u32 = rata_temporis.possible_service_days(PensionAge::just_65()).unwrap();
+    /// assert_eq!(n, 45 * 365 + 12);
+    /// assert_eq!(n, 16_437);
+    ///
+    /// // Pension date is 31.12.2064, one day earlier
+    /// let birth_date: Date = Date::new_num(1999, 12, 31).unwrap();
+    /// let rata_temporis: RataTemporis = RataTemporis::new(birth_date, entry_date, exit_date).unwrap();
+    /// let n: u32 = rata_temporis.possible_service_days(PensionAge::just_65()).unwrap();
+    /// assert_eq!(n, 16_436);
+    ///
+    /// // Entry on the pension date
+    /// let birth_date: Date = Date::new_num(1955, 1, 1).unwrap();
+    /// let rata_temporis: RataTemporis = RataTemporis::new(birth_date, entry_date, exit_date).unwrap();
+    /// let n: u32 = rata_temporis.possible_service_days(PensionAge::just_65()).unwrap();
+    /// assert_eq!(n, 0);
+    /// ```
+    pub fn possible_service_days(&self, pension_age: PensionAge) -> Result<u32, RataTemporisError> {
+        let pension_date: Date = self.pension_date(pension_age)?;
+
+        RataTemporisError::check_order(&self.entry_date, &pension_date)?;
+
+        let n: u32 = self
+            .entry_date
+            .day_difference(&pension_date)
+            .try_into()
+            .map_err(|_| RataTemporisError::NegativeDifference)?;
+
+        Ok(n)
+    }
+
+    /// Returns the possible service (n) as a fixed number of `years` after the `entry_date`.
+    ///
+    /// Unlike [`RataTemporis::possible_service`] this does not depend on the `birth_date`,
+    /// which models flat vesting schedules.
+    ///
+    /// # Errors
+    ///
+    /// * [`RataTemporisError::YearError`] - The addition of `years` to the `entry_date` went wrong.
+    /// * [`RataTemporisError::NegativeDifference`] - The difference is negative, which should never happen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, RataTemporis, RataTemporisError, Accuracy, Rounding};
+    /// let birth_date: Date = Date::new_num(2000, 1, 1).unwrap();
+    /// let entry_date: Date = Date::new_num(2020, 1, 1).unwrap();
+    /// let exit_date: Date = Date::new_num(2025, 12, 31).unwrap();
+    /// let rata_temporis: RataTemporis = RataTemporis::new(birth_date, entry_date, exit_date).unwrap();
+    ///
+    /// // DayExact
+    /// let n: u32 = rata_temporis.possible_service_fixed_years(45, Accuracy::DayExact, Rounding::Floor).unwrap();
+    /// assert_eq!(n, 45 * 365 + 12);
+    ///
+    /// // MonthExact
+    /// let n: u32 = rata_temporis.possible_service_fixed_years(45, Accuracy::MonthExact, Rounding::Floor).unwrap();
+    /// assert_eq!(n, 540);
+    ///
+    /// // YearError
+    /// let year_error: RataTemporisError = rata_temporis.possible_service_fixed_years(100, Accuracy::DayExact, Rounding::Floor).err().unwrap();
+    /// assert_eq!(year_error, RataTemporisError::YearError { pension_years: 100 });
+    /// ```
+    pub fn possible_service_fixed_years(
+        &self,
+        years: u32,
+        accuracy: Accuracy,
+        rounding: Rounding,
+    ) -> Result<u32, RataTemporisError> {
+        let pension_years: i32 = i32::try_from(years).unwrap_or(i32::MAX);
+        let year_error: RataTemporisError = RataTemporisError::YearError { pension_years };
+
+        let end_date: Date = pension_years
+            .checked_mul(12_i32)
+            .ok_or(year_error)
+            .and_then(|months| self.entry_date.add_months(months).map_err(|_| year_error))?;
+
+        // Can not be negative
+        let n: u32 = self
+            .entry_date
+            .difference_in(&end_date, accuracy, rounding)
+            .try_into()
+            .map_err(|_| RataTemporisError::NegativeDifference)?;
+
+        Ok(n)
+    }
+
+    /// Returns the `pension_date` based on a given [`PensionAge`].
+    ///
+    /// This is the date [`PensionYears`] years and [`PensionMonths`] months after the `birth_date`.
+    ///
+    /// # Errors
+    ///
+    /// * [`RataTemporisError::YearError`] - The addition of `pension_years` went wrong.
+    /// * [`RataTemporisError::MonthError`] - The addition of `pension_months` went wrong.
+    #[inline]
+    fn pension_date(&self, pension_age: PensionAge) -> Result<Date, RataTemporisError> {
+        let pension_years: i32 = i32::from(pension_age.pension_years());
+        let pension_months: i32 = i32::from(pension_age.pension_months());
+
+        self.birth_date
+            .add_years(pension_years)
+            .map_err(|_| RataTemporisError::YearError { pension_years })?
+            .add_months(pension_months)
+            .map_err(|_| RataTemporisError::MonthError { pension_months })
+    }
+
     /// Returns the possible service (n) based on the legal [`PensionAge`].
     ///
     /// The [`PensionAge`] is automatically calculated based on the birthyear using [`PensionAge::from_birthyear`].
@@ -397,6 +528,265 @@ impl RataTemporis {
 
         self.rata_temporis(pension_age, accuracy, rounding)
     }
+
+    /// Returns the "Unverfallbarkeitsquote" according to §2 BetrAVG.
+    ///
+    /// This is the [`RataTemporis::rata_temporis`] capped to the interval [0, 1].
+    /// An `exit_date` after the `pension_date` would otherwise yield a ratio larger than 1.
+    /// This is the recommended entry point for reporting vested entitlements.
+    ///
+    /// # Errors
+    ///
+    /// See [`RataTemporis::rata_temporis`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, RataTemporis, Accuracy, PensionAge, Rounding};
+    /// let pension_age: PensionAge = PensionAge::just_65();
+    ///
+    /// // Regular
+    /// let birth_date: Date = Date::new_num(2000, 1, 1).unwrap();
+    /// let entry_date: Date = Date::new_num(2020, 1, 1).unwrap();
+    /// let exit_date: Date = Date::new_num(2025, 12, 31).unwrap();
+    /// let rata_temporis: RataTemporis = RataTemporis::new(birth_date, entry_date, exit_date).unwrap();
+    /// let quote: f64 = rata_temporis.unverfallbarkeitsquote(pension_age, Accuracy::MonthExact, Rounding::Floor).unwrap();
+    /// assert!((quote - 71. / 540.).abs() < f64::EPSILON);
+    ///
+    /// // Exit after the pension date
+    /// let birth_date: Date = Date::new_num(1960, 1, 1).unwrap();
+    /// let entry_date: Date = Date::new_num(2020, 1, 1).unwrap();
+    /// let exit_date: Date = Date::new_num(2030, 1, 1).unwrap();
+    /// let rata_temporis: RataTemporis = RataTemporis::new(birth_date, entry_date, exit_date).unwrap();
+    /// let rata: f64 = rata_temporis.rata_temporis(pension_age, Accuracy::MonthExact, Rounding::Floor).unwrap();
+    /// assert!((rata - 2.0).abs() < f64::EPSILON);
+    /// let quote: f64 = rata_temporis.unverfallbarkeitsquote(pension_age, Accuracy::MonthExact, Rounding::Floor).unwrap();
+    /// assert!((quote - 1.0).abs() < f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn unverfallbarkeitsquote(
+        &self,
+        pension_age: PensionAge,
+        accuracy: Accuracy,
+        rounding: Rounding,
+    ) -> Result<f64, RataTemporisError> {
+        let rata: f64 = self.rata_temporis(pension_age, accuracy, rounding)?;
+
+        Ok(rata.clamp(0.0, 1.0))
+    }
+
+    /// Returns the unvested fraction, which is the complement of [`RataTemporis::unverfallbarkeitsquote`].
+    ///
+    /// This is `1 - quote` and therefore also inside the interval [0, 1].
+    ///
+    /// # Errors
+    ///
+    /// See [`RataTemporis::rata_temporis`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, RataTemporis, Accuracy, PensionAge, Rounding};
+    /// let birth_date: Date = Date::new_num(2000, 1, 1).unwrap();
+    /// let entry_date: Date = Date::new_num(2020, 1, 1).unwrap();
+    /// let exit_date: Date = Date::new_num(2025, 12, 31).unwrap();
+    /// let rata_temporis: RataTemporis = RataTemporis::new(birth_date, entry_date, exit_date).unwrap();
+    /// let pension_age: PensionAge = PensionAge::just_65();
+    ///
+    /// let vested: f64 = rata_temporis.unverfallbarkeitsquote(pension_age, Accuracy::MonthExact, Rounding::Floor).unwrap();
+    /// let unvested: f64 = rata_temporis.unvested_fraction(pension_age, Accuracy::MonthExact, Rounding::Floor).unwrap();
+    /// assert!((unvested - 469. / 540.).abs() < f64::EPSILON);
+    /// assert!((vested + unvested - 1.0).abs() < f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn unvested_fraction(
+        &self,
+        pension_age: PensionAge,
+        accuracy: Accuracy,
+        rounding: Rounding,
+    ) -> Result<f64, RataTemporisError> {
+        let quote: f64 = self.unverfallbarkeitsquote(pension_age, accuracy, rounding)?;
+
+        Ok((1.0 - quote).clamp(0.0, 1.0))
+    }
+
+    /// Returns the [`RataTemporis`] ratio to be used as a sort key.
+    ///
+    /// This is the same as [`RataTemporis::rata_temporis`], but makes the intent explicit.
+    /// Note that [`PartialEq`] compares the [`Date`]s and not the ratio.
+    ///
+    /// # Errors
+    ///
+    /// See [`RataTemporis::rata_temporis`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, RataTemporis, Accuracy, PensionAge, Rounding};
+    /// let birth_date: Date = Date::new_num(2000, 1, 1).unwrap();
+    /// let exit_date: Date = Date::new_num(2025, 12, 31).unwrap();
+    /// let long: RataTemporis = RataTemporis::new(birth_date, Date::new_num(2018, 1, 1).unwrap(), exit_date).unwrap();
+    /// let short: RataTemporis = RataTemporis::new(birth_date, Date::new_num(2024, 1, 1).unwrap(), exit_date).unwrap();
+    /// let medium: RataTemporis = RataTemporis::new(birth_date, Date::new_num(2021, 1, 1).unwrap(), exit_date).unwrap();
+    /// let pension_age: PensionAge = PensionAge::just_65();
+    ///
+    /// let mut employees: Vec<RataTemporis> = vec![long, short, medium];
+    /// employees.sort_by(|a, b| {
+    ///     let a: f64 = a.ratio_key(pension_age, Accuracy::MonthExact, Rounding::Floor).unwrap();
+    ///     let b: f64 = b.ratio_key(pension_age, Accuracy::MonthExact, Rounding::Floor).unwrap();
+    ///     a.total_cmp(&b)
+    /// });
+    /// assert_eq!(employees, vec![short, medium, long]);
+    /// ```
+    #[inline]
+    pub fn ratio_key(
+        &self,
+        pension_age: PensionAge,
+        accuracy: Accuracy,
+        rounding: Rounding,
+    ) -> Result<f64, RataTemporisError> {
+        self.rata_temporis(pension_age, accuracy, rounding)
+    }
+
+    /// Returns the smallest and largest [`RataTemporis`] across all [`Rounding`] modes.
+    ///
+    /// This uses [`RataTemporis::rata_temporis`] with [`Rounding::Floor`], [`Rounding::Nearest`] and [`Rounding::Ceil`].
+    /// A large spread flags a borderline case which might need a manual review.
+    ///
+    /// # Errors
+    ///
+    /// See [`RataTemporis::rata_temporis`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, RataTemporis, Accuracy, PensionAge, Rounding};
+    /// let birth_date: Date = Date::new_num(2000, 1, 1).unwrap();
+    /// let entry_date: Date = Date::new_num(2020, 1, 1).unwrap();
+    /// let exit_date: Date = Date::new_num(2025, 12, 31).unwrap();
+    /// let rata_temporis: RataTemporis = RataTemporis::new(birth_date, entry_date, exit_date).unwrap();
+    /// let pension_age: PensionAge = PensionAge::just_65();
+    ///
+    /// let (min, max): (f64, f64) = rata_temporis.rounding_spread(pension_age, Accuracy::MonthExact).unwrap();
+    /// let floor: f64 = rata_temporis.rata_temporis(pension_age, Accuracy::MonthExact, Rounding::Floor).unwrap();
+    /// let ceil: f64 = rata_temporis.rata_temporis(pension_age, Accuracy::MonthExact, Rounding::Ceil).unwrap();
+    /// assert!((min - floor).abs() < f64::EPSILON);
+    /// assert!((max - ceil).abs() < f64::EPSILON);
+    /// assert!((max - 72. / 540.).abs() < f64::EPSILON);
+    /// ```
+    pub fn rounding_spread(
+        &self,
+        pension_age: PensionAge,
+        accuracy: Accuracy,
+    ) -> Result<(f64, f64), RataTemporisError> {
+        let mut min: f64 = f64::INFINITY;
+        let mut max: f64 = f64::NEG_INFINITY;
+
+        for rounding in [Rounding::Floor, Rounding::Nearest, Rounding::Ceil] {
+            let rata: f64 = self.rata_temporis(pension_age, accuracy, rounding)?;
+            min = min.min(rata);
+            max = max.max(rata);
+        }
+
+        Ok((min, max))
+    }
+
+    /// Returns the [`RataTemporis`] for a batch of `(birth_date, entry_date, exit_date)` inputs.
+    ///
+    /// Each row is created with [`RataTemporis::new`] and evaluated with [`RataTemporis::rata_temporis_birthyear`].
+    /// Any error is collected for its row, so a single bad row does not stop the batch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, RataTemporis, RataTemporisError, Accuracy, Rounding};
+    /// let birth_date: Date = Date::new_num(2000, 1, 1).unwrap();
+    /// let entry_date: Date = Date::new_num(2020, 1, 1).unwrap();
+    /// let exit_date: Date = Date::new_num(2025, 12, 31).unwrap();
+    ///
+    /// let inputs: [(Date, Date, Date); 2] = [
+    ///     (birth_date, entry_date, exit_date),
+    ///     // Entry after exit
+    ///     (birth_date, exit_date, entry_date),
+    /// ];
+    /// let results: Vec<Result<f64, RataTemporisError>> = RataTemporis::batch(&inputs, Accuracy::MonthExact, Rounding::Floor);
+    ///
+    /// assert!((results[0].unwrap() - 71. / 564.).abs() < f64::EPSILON);
+    /// assert_eq!(results[1], Err(RataTemporisError::WrongOrder { first_date: exit_date, second_date: entry_date }));
+    /// ```
+    pub fn batch(
+        inputs: &[(Date, Date, Date)],
+        accuracy: Accuracy,
+        rounding: Rounding,
+    ) -> Vec<Result<f64, RataTemporisError>> {
+        inputs
+            .iter()
+            .map(|&(birth_date, entry_date, exit_date)| {
+                Self::new(birth_date, entry_date, exit_date)?
+                    .rata_temporis_birthyear(accuracy, rounding)
+            })
+            .collect()
+    }
+
+    /// Returns the average "Unverfallbarkeitsquote" of multiple [`RataTemporis`] instances.
+    ///
+    /// Each quote is calculated with [`RataTemporis::unverfallbarkeitsquote`]
+    /// using the [`PensionAge::from_birthyear`] of the respective birth date.
+    /// If the `items` are empty, [`None`] is returned.
+    ///
+    /// # Errors
+    ///
+    /// * Any error of [`RataTemporis::unverfallbarkeitsquote`] for a single item.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, RataTemporis, Accuracy, Rounding};
+    /// let entry_date: Date = Date::new_num(2020, 1, 1).unwrap();
+    ///
+    /// // Valid
+    /// let birth_date: Date = Date::new_num(2000, 1, 1).unwrap();
+    /// let exit_date: Date = Date::new_num(2025, 12, 31).unwrap();
+    /// let regular: RataTemporis = RataTemporis::new(birth_date, entry_date, exit_date).unwrap();
+    ///
+    /// // Exit after the pension date, so capped to 1
+    /// let birth_date: Date = Date::new_num(1960, 1, 1).unwrap();
+    /// let exit_date: Date = Date::new_num(2030, 1, 1).unwrap();
+    /// let capped: RataTemporis = RataTemporis::new(birth_date, entry_date, exit_date).unwrap();
+    ///
+    /// let average: f64 = RataTemporis::average_rata_temporis(&[regular, capped], Accuracy::MonthExact, Rounding::Floor).unwrap().unwrap();
+    /// assert!((average - (71. / 564. + 1.) / 2.).abs() < f64::EPSILON);
+    ///
+    /// // Empty
+    /// assert_eq!(RataTemporis::average_rata_temporis(&[], Accuracy::MonthExact, Rounding::Floor), Ok(None));
+    /// ```
+    pub fn average_rata_temporis(
+        items: &[RataTemporis],
+        accuracy: Accuracy,
+        rounding: Rounding,
+    ) -> Result<Option<f64>, RataTemporisError> {
+        if items.is_empty() {
+            return Ok(None);
+        }
+
+        let mut sum: f64 = 0.0;
+        for item in items {
+            let pension_age: PensionAge = PensionAge::from_birthyear(item.birth_date.year());
+            sum += item.unverfallbarkeitsquote(pension_age, accuracy, rounding)?;
+        }
+
+        Ok(Some(sum / items.len() as f64))
+    }
+}
+
+impl Display for RataTemporis {
+    fn fmt(&self, format: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            format,
+            "RataTemporis(birth={}, entry={}, exit={})",
+            self.birth_date, self.entry_date, self.exit_date
+        )
+    }
 }
 
 /// An enum for handling any errors involved in the calculation of [`RataTemporis`].
@@ -418,6 +808,31 @@ pub enum RataTemporisError {
 }
 
 impl RataTemporisError {
+    /// Checks if the [`RataTemporisError`] is caused by bad input, which can be fixed by the caller.
+    ///
+    /// Only [`RataTemporisError::NegativeDifference`] is not an input error, as it should never happen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use date::{Date, RataTemporisError};
+    /// let first_date: Date = Date::new_num(2025, 1, 1).unwrap();
+    /// let second_date: Date = Date::new_num(2020, 1, 1).unwrap();
+    /// assert!(RataTemporisError::WrongOrder { first_date, second_date }.is_input_error());
+    /// assert!(RataTemporisError::YearError { pension_years: 67 }.is_input_error());
+    /// assert!(RataTemporisError::MonthError { pension_months: 11 }.is_input_error());
+    /// assert!(!RataTemporisError::NegativeDifference.is_input_error());
+    /// ```
+    #[inline]
+    pub const fn is_input_error(&self) -> bool {
+        match self {
+            RataTemporisError::WrongOrder { .. }
+            | RataTemporisError::YearError { .. }
+            | RataTemporisError::MonthError { .. } => true,
+            RataTemporisError::NegativeDifference => false,
+        }
+    }
+
     /// Checks if two [`Date`]s are correctly ordered.
     ///
     /// `first_date` must be before `second_date`.
@@ -8,9 +8,10 @@ use crate::{Date, RataTemporis};
 /// This is used in [`Date::month_difference`] and [`Date::year_difference`] and
 /// therefore [Date::actuarial_age], [`Date::civil_age`].
 /// Furthermore, it is used in all methods of the [`RataTemporis`] struct.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub enum Rounding {
     /// Rounds to the nearest whole unit, with halves rounds up.
+    #[default]
     Nearest,
 
     /// Rounds down to the nearest whole unit.
@@ -20,12 +21,6 @@ pub enum Rounding {
     Ceil,
 }
 
-impl Default for Rounding {
-    fn default() -> Self {
-        Rounding::Nearest
-    }
-}
-
 impl Display for Rounding {
     fn fmt(&self, format: &mut Formatter<'_>) -> std::fmt::Result {
         match self {